@@ -0,0 +1,79 @@
+//! Compares A*, Jump Point Search and JPS+ on uniform-cost maps.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::{floor, lcg, random_map, unit};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use nav::{Direction, Grid, Heuristic, Move, Octant};
+use std::hint::black_box;
+
+/// Number of rows and columns of the benchmark maps.
+const DIM: usize = 2048;
+
+/// A deterministic pseudo-random map of the given number of rectangular walls.
+fn block_map(seed: u64, dim: (usize, usize), blocks: usize) -> Grid<char> {
+    let mut state = seed;
+    let mut grid = Grid::new(dim, '.');
+    for _ in 0..blocks {
+        let mut next = |modulus: usize| usize::try_from(lcg(&mut state)).unwrap() % modulus;
+        let (row, col, height, width) = (next(dim.0), next(dim.1), 1 + next(24), 1 + next(24));
+        for r in row..(row + height).min(dim.0) {
+            for c in col..(col + width).min(dim.1) {
+                grid[(r, c)] = '#';
+            }
+        }
+    }
+    grid
+}
+
+/// Benchmarks each search between opposite corners of the map, checking that all find paths of the same length.
+fn compare<M: Move>(criterion: &mut Criterion, name: &str, heuristic: Heuristic) {
+    let mut group = criterion.benchmark_group(name);
+    let _ = group.sample_size(10);
+    let maps = [
+        ("open", block_map(0, (DIM, DIM), 0)),
+        ("blocks", block_map(1, (DIM, DIM), DIM * DIM / 512)),
+        ("noise", random_map(3, (DIM, DIM), 1)),
+    ];
+    for (map, mut grid) in maps {
+        let (start, goal) = ((0, 0), (DIM - 1, DIM - 1));
+        grid[start] = '.';
+        grid[goal] = '.';
+        let grid = &grid;
+        let table = grid.jump_table::<M, _>(floor);
+
+        let astar = grid.astar::<M, _>(start, goal, heuristic, unit);
+        let jps = grid.jps::<M, _>(start, goal, floor);
+        let jps_plus = table.path(start, goal);
+        let (_, length) = astar.expect("the corners of every map are connected");
+        for found in [jps, jps_plus] {
+            let (_, found) = found.unwrap();
+            assert!((found - length).abs() < 1e-9, "{found} != {length}");
+        }
+
+        let _ = group.bench_with_input(BenchmarkId::new("astar", map), grid, |bench, grid| {
+            bench.iter(|| grid.astar::<M, _>(black_box(start), black_box(goal), heuristic, unit));
+        });
+        let _ = group.bench_with_input(BenchmarkId::new("jps", map), grid, |bench, grid| {
+            bench.iter(|| grid.jps::<M, _>(black_box(start), black_box(goal), floor));
+        });
+        let _ = group.bench_with_input(BenchmarkId::new("jps_plus", map), &table, |bench, table| {
+            bench.iter(|| table.path(black_box(start), black_box(goal)));
+        });
+    }
+    group.finish();
+}
+
+/// Benchmarks 4-connected searches.
+fn cardinal(criterion: &mut Criterion) {
+    compare::<Direction>(criterion, "cardinal", Heuristic::Manhattan);
+}
+
+/// Benchmarks 8-connected searches.
+fn octant(criterion: &mut Criterion) {
+    compare::<Octant>(criterion, "octant", Heuristic::Octile);
+}
+
+criterion_group!(benches, cardinal, octant);
+criterion_main!(benches);
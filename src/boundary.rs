@@ -0,0 +1,97 @@
+//! ## `boundary`
+//!
+//! The `boundary` module provides the `Boundary` enum, which decides what happens when a step leaves the edge of a
+//! bounded grid.
+
+use core::ops::Mul;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Convention, Coord, Octant, Transform};
+
+/// A policy for steps which would leave a grid of `(rows, cols)` dimensions.
+///
+/// Positions are `(row, col)` indices with rows increasing downwards, as used by `Transform::apply_index`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Boundary {
+    /// Everything beyond the edge is void, so the step fails.
+    #[default]
+    Bounded,
+    /// Opposite edges are joined, as on a torus.
+    Wrap,
+    /// The edge is a mirror: the position stays put on the axis which hit it, and the heading is reflected.
+    Reflect,
+    /// The position stays put on the axis which hit the edge, and the heading is kept.
+    Clamp,
+}
+
+impl Boundary {
+    /// Steps `pos` one unit in the given heading, returning the new position and heading, or `None` if the step fails
+    /// or `pos` lies outside the grid.
+    ///
+    /// The heading is only changed by `Reflect`, which applies `Transform::FlipVertical` after hitting the top or
+    /// bottom edge and `Transform::FlipHorizontal` after hitting the left or right edge. A diagonal step into a corner
+    /// is reflected by both.
+    #[must_use]
+    pub fn step<D>(self, (rows, cols): (usize, usize), (row, col): (usize, usize), heading: D) -> Option<((usize, usize), D)>
+    where
+        D: Copy + Into<Octant> + Mul<Transform, Output = D>,
+    {
+        if row >= rows || col >= cols {
+            return None;
+        }
+        let (dx, dy) = heading.into().to_vector_in::<i8>(Convention::SCREEN);
+        let (row, reflect_row) = self.step_axis(rows, row, dy)?;
+        let (col, reflect_col) = self.step_axis(cols, col, dx)?;
+
+        let mut heading = heading;
+        if reflect_row {
+            heading = heading * Transform::FlipVertical;
+        }
+        if reflect_col {
+            heading = heading * Transform::FlipHorizontal;
+        }
+        Some(((row, col), heading))
+    }
+
+    /// Steps a `Coord` one unit in the given heading within a grid of `(rows, cols)` dimensions, returning the new
+    /// `Coord` and heading, or `None` if the step fails or `coord` lies outside the grid.
+    ///
+    /// The y-axis of a `Coord` points North, so `x` ranges over the columns and `y` over the rows counted upwards from
+    /// the bottom row, as for `Grid::position`. Edges are handled as by `step`.
+    #[must_use]
+    pub fn step_coord<D>(self, (rows, cols): (usize, usize), coord: Coord, heading: D) -> Option<(Coord, D)>
+    where
+        D: Copy + Into<Octant> + Mul<Transform, Output = D>,
+    {
+        let col = usize::try_from(coord.x).ok()?;
+        let row = rows.checked_sub(1)?.checked_sub(usize::try_from(coord.y).ok()?)?;
+        let ((row, col), heading) = self.step((rows, cols), (row, col), heading)?;
+        let (x, y) = (i32::try_from(col).ok()?, i32::try_from(rows - 1 - row).ok()?);
+        Some((Coord::new(x, y), heading))
+    }
+
+    /// Steps an index along an axis of length `len` by `delta`, which is `-1`, `0` or `1`, returning the new index and
+    /// whether the step was reflected, or `None` if the step fails.
+    const fn step_axis(self, len: usize, index: usize, delta: i8) -> Option<(usize, bool)> {
+        let target = match delta {
+            0 => return Some((index, false)),
+            ..0 => index.checked_sub(1),
+            _ => {
+                if index + 1 < len {
+                    Some(index + 1)
+                } else {
+                    None
+                }
+            }
+        };
+        match (target, self) {
+            (Some(target), _) => Some((target, false)),
+            (None, Self::Bounded) => None,
+            (None, Self::Wrap) => Some((if delta < 0 { len - 1 } else { 0 }, false)),
+            (None, Self::Reflect) => Some((index, true)),
+            (None, Self::Clamp) => Some((index, false)),
+        }
+    }
+}
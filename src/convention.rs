@@ -0,0 +1,75 @@
+//! ## `convention`
+//!
+//! The `convention` module provides the `Convention` struct, which describes the coordinate frame used when converting
+//! `Direction`s, `Octant`s, `Coord`s and `Transform`s to and from angles, vectors and matrices.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The way the y-axis points relative to `Direction::North`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum YAxis {
+    /// The y-axis points North, as in mathematics.
+    Up,
+    /// The y-axis points South, as in screen space and row-major arrays.
+    Down,
+}
+
+/// The way in which angles increase.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Winding {
+    /// Angles increase from `East` towards `South`.
+    Clockwise,
+    /// Angles increase from `East` towards `North`.
+    CounterClockwise,
+}
+
+/// A coordinate frame for angle, vector and matrix conversions.
+///
+/// `Direction`s and `Transform`s always keep their compass meaning: `North` is up and `Rotate90` turns a quarter
+/// clockwise. The `Convention` only decides how that meaning is written down as numbers. Angles are always measured
+/// from `East`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Convention {
+    /// Direction of the positive y-axis.
+    pub y_axis: YAxis,
+    /// Direction in which angles increase.
+    pub winding: Winding,
+}
+
+impl Convention {
+    /// The mathematical convention used by the methods without a `Convention` argument: the y-axis points North and
+    /// angles increase counter-clockwise.
+    pub const MATH: Self = Self::new(YAxis::Up, Winding::CounterClockwise);
+    /// The screen-space convention: the y-axis points South and angles increase clockwise, so that angles agree with
+    /// `atan2(dy, dx)` of screen-space vectors.
+    pub const SCREEN: Self = Self::new(YAxis::Down, Winding::Clockwise);
+
+    /// Constructs a new `Convention` from its y-axis and winding.
+    #[must_use]
+    pub const fn new(y_axis: YAxis, winding: Winding) -> Self {
+        Self { y_axis, winding }
+    }
+
+    /// Returns true if the y-axis points South.
+    #[must_use]
+    pub const fn is_y_down(self) -> bool {
+        matches!(self.y_axis, YAxis::Down)
+    }
+
+    /// Returns true if angles increase clockwise.
+    #[must_use]
+    pub const fn is_clockwise(self) -> bool {
+        matches!(self.winding, Winding::Clockwise)
+    }
+}
+
+impl Default for Convention {
+    /// Returns `Convention::MATH`.
+    fn default() -> Self {
+        Self::MATH
+    }
+}
@@ -0,0 +1,42 @@
+//! ## `conversion_error`
+//!
+//! The `conversion_error` module provides the `ConversionError` enum, returned when a value has no equivalent in the
+//! type it is converted into.
+
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use crate::{Octant, Transform};
+
+/// An error produced when a fallible conversion between the crate's types fails, carrying the rejected value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum ConversionError {
+    /// A diagonal `Octant` has no equivalent `Direction`.
+    DiagonalOctant(Octant),
+    /// Bits above the lowest four are set, so the value is not a valid `DirectionSet`.
+    InvalidDirectionSetBits(u8),
+    /// A flip `Transform` has no equivalent `Turn`.
+    FlipTransform(Transform),
+    /// The serialized parts of a `JumpTable` do not describe a consistent table.
+    InconsistentJumpTable,
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DiagonalOctant(octant) => write!(fmt, "Diagonal octant {octant} has no equivalent direction"),
+            Self::InvalidDirectionSetBits(bits) => {
+                write!(
+                    fmt,
+                    "Invalid direction set bits {bits:#010b}, only the lowest four bits may be set"
+                )
+            }
+            Self::FlipTransform(transform) => write!(fmt, "Flip transform {transform} has no equivalent turn"),
+            Self::InconsistentJumpTable => write!(fmt, "Jump table does not match its dimensions"),
+        }
+    }
+}
+
+impl Error for ConversionError {}
@@ -0,0 +1,272 @@
+//! ## `coord`
+//!
+//! The `coord` module provides the `Coord` struct, an integer point on the 2D grid that `Direction`, `Octant` and
+//! `Transform` act upon.
+
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Convention, Direction, Octant, Transform};
+
+/// An integer coordinate on the 2D grid.
+///
+/// The x-axis points East and the y-axis points North, so that `Direction::North` is the unit step `(0, 1)`.
+/// `Transform`s act on a `Coord` exactly as documented on each `Transform` variant.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Coord {
+    /// Horizontal component, increasing towards the East.
+    pub x: i32,
+    /// Vertical component, increasing towards the North.
+    pub y: i32,
+}
+
+impl Coord {
+    /// The origin `(0, 0)`.
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// Constructs a new `Coord` from its components.
+    #[must_use]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the `Direction` of a unit cardinal step, or `None` if the `Coord` is not one.
+    #[must_use]
+    pub const fn direction(self) -> Option<Direction> {
+        match (self.x, self.y) {
+            (0, 1) => Some(Direction::North),
+            (1, 0) => Some(Direction::East),
+            (0, -1) => Some(Direction::South),
+            (-1, 0) => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Octant` of a unit step including diagonals, or `None` if the `Coord` is not one.
+    #[must_use]
+    pub const fn octant(self) -> Option<Octant> {
+        match (self.x, self.y) {
+            (0, 1) => Some(Octant::North),
+            (1, 1) => Some(Octant::NorthEast),
+            (1, 0) => Some(Octant::East),
+            (1, -1) => Some(Octant::SouthEast),
+            (0, -1) => Some(Octant::South),
+            (-1, -1) => Some(Octant::SouthWest),
+            (-1, 0) => Some(Octant::West),
+            (-1, 1) => Some(Octant::NorthWest),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Direction` of a unit cardinal step in the frame of the `Convention`, or `None` if the `Coord` is
+    /// not one.
+    #[must_use]
+    pub const fn direction_in(self, convention: Convention) -> Option<Direction> {
+        self.to_math(convention).direction()
+    }
+
+    /// Returns the `Octant` of a unit step including diagonals in the frame of the `Convention`, or `None` if the
+    /// `Coord` is not one.
+    #[must_use]
+    pub const fn octant_in(self, convention: Convention) -> Option<Octant> {
+        self.to_math(convention).octant()
+    }
+
+    /// Steps the `Coord`, given in the frame of the `Convention`, one unit in the given `Direction`.
+    #[must_use]
+    pub const fn step_in(self, direction: Direction, convention: Convention) -> Self {
+        let Self { x, y } = Self::from_direction(direction).to_math(convention);
+        Self::new(self.x + x, self.y + y)
+    }
+
+    /// Applies a `Transform` about the origin to the `Coord`, given in the frame of the `Convention`.
+    ///
+    /// The `Transform` keeps its compass meaning, so `Rotate90` always turns `North` to `East`.
+    #[must_use]
+    pub fn transform_in(self, transform: Transform, convention: Convention) -> Self {
+        (self.to_math(convention) * transform).to_math(convention)
+    }
+
+    /// Converts the `Coord` between the frame of the `Convention` and the y-up frame, in either direction.
+    const fn to_math(self, convention: Convention) -> Self {
+        if convention.is_y_down() {
+            Self::new(self.x, -self.y)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the unit step of a `Direction`, with the y-axis pointing North.
+    const fn from_direction(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Self::new(0, 1),
+            Direction::East => Self::new(1, 0),
+            Direction::South => Self::new(0, -1),
+            Direction::West => Self::new(-1, 0),
+        }
+    }
+
+    /// Returns the Manhattan (taxicab) length of the `Coord`.
+    #[must_use]
+    pub const fn manhattan(self) -> u32 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Returns the Chebyshev (chessboard) length of the `Coord`.
+    #[must_use]
+    pub const fn chebyshev(self) -> u32 {
+        let (x, y) = (self.x.unsigned_abs(), self.y.unsigned_abs());
+        if x > y { x } else { y }
+    }
+}
+
+impl From<(i32, i32)> for Coord {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Coord> for (i32, i32) {
+    fn from(coord: Coord) -> Self {
+        (coord.x, coord.y)
+    }
+}
+
+impl From<Direction> for Coord {
+    fn from(direction: Direction) -> Self {
+        Self::from_direction(direction)
+    }
+}
+
+impl From<Octant> for Coord {
+    fn from(octant: Octant) -> Self {
+        match octant {
+            Octant::North => Self::new(0, 1),
+            Octant::NorthEast => Self::new(1, 1),
+            Octant::East => Self::new(1, 0),
+            Octant::SouthEast => Self::new(1, -1),
+            Octant::South => Self::new(0, -1),
+            Octant::SouthWest => Self::new(-1, -1),
+            Octant::West => Self::new(-1, 0),
+            Octant::NorthWest => Self::new(-1, 1),
+        }
+    }
+}
+
+impl Add for Coord {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Add<Direction> for Coord {
+    type Output = Self;
+
+    /// Steps the `Coord` one unit in the given `Direction`.
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + Self::from(rhs)
+    }
+}
+
+impl AddAssign<Direction> for Coord {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl Add<Octant> for Coord {
+    type Output = Self;
+
+    /// Steps the `Coord` one unit in the given `Octant`, moving diagonally for the diagonal variants.
+    fn add(self, rhs: Octant) -> Self::Output {
+        self + Self::from(rhs)
+    }
+}
+
+impl AddAssign<Octant> for Coord {
+    fn add_assign(&mut self, rhs: Octant) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Self;
+
+    /// Returns the delta which steps `rhs` onto `self`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Direction> for Coord {
+    type Output = Self;
+
+    /// Steps the `Coord` one unit against the given `Direction`.
+    fn sub(self, rhs: Direction) -> Self::Output {
+        self - Self::from(rhs)
+    }
+}
+
+impl SubAssign<Direction> for Coord {
+    fn sub_assign(&mut self, rhs: Direction) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<Transform> for Coord {
+    type Output = Self;
+
+    /// Applies a `Transform` to the `Coord` about the origin.
+    fn mul(self, rhs: Transform) -> Self::Output {
+        let Self { x, y } = self;
+        match rhs {
+            Transform::Identity => Self::new(x, y),
+            Transform::Rotate90 => Self::new(y, -x),
+            Transform::Rotate180 => Self::new(-x, -y),
+            Transform::Rotate270 => Self::new(-y, x),
+            Transform::FlipHorizontal => Self::new(-x, y),
+            Transform::FlipDiagonal => Self::new(y, x),
+            Transform::FlipVertical => Self::new(x, -y),
+            Transform::FlipAntiDiagonal => Self::new(-y, -x),
+        }
+    }
+}
+
+impl MulAssign<Transform> for Coord {
+    fn mul_assign(&mut self, rhs: Transform) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for Coord {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        write!(fmt, "({}, {})", self.x, self.y)
+    }
+}
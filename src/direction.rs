@@ -2,16 +2,18 @@
 //!
 //! The `direction` module provides the `Direction` enum, which represents the four cardinal directions: North, East, South, and West.
 
-use num_enum::{IntoPrimitive, TryFromPrimitive};
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-use std::{
+use core::{
+    f64::consts::{FRAC_PI_2, PI},
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{Mul, MulAssign, Neg},
     str::FromStr,
 };
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use num_traits::{Signed, float::FloatCore};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use crate::Transform;
+use crate::{Convention, ParseError, Transform};
 
 /// All cardinal directions in their order of definition.
 pub const ALL_DIRECTIONS: [Direction; 4] = [
@@ -48,6 +50,82 @@ impl Direction {
     pub const fn is_horizontal(self) -> bool {
         matches!(self, Self::East | Self::West)
     }
+
+    /// Returns the angle of the `Direction` in radians, measured counter-clockwise from `East` with the y-axis
+    /// pointing North, so that `North` is `PI / 2`.
+    ///
+    /// The result lies in `[0, 2 * PI)`; use `f64::to_degrees` for degrees.
+    #[must_use]
+    pub const fn to_angle(self) -> f64 {
+        self.to_angle_in(Convention::MATH)
+    }
+
+    /// Returns the angle of the `Direction` in radians, measured from `East` in the winding of the `Convention`.
+    ///
+    /// The result lies in `[0, 2 * PI)`, so that `North` is `PI / 2` counter-clockwise and `3 * PI / 2` clockwise.
+    #[must_use]
+    pub const fn to_angle_in(self, convention: Convention) -> f64 {
+        match (self, convention.is_clockwise()) {
+            (Self::East, _) => 0.0,
+            (Self::North, false) | (Self::South, true) => FRAC_PI_2,
+            (Self::West, _) => PI,
+            (Self::South, false) | (Self::North, true) => 3.0 * FRAC_PI_2,
+        }
+    }
+
+    /// Returns the `Direction` nearest to an angle in radians, measured counter-clockwise from `East` with the
+    /// y-axis pointing North, or `None` if the angle is not finite.
+    ///
+    /// Angles of any magnitude are accepted. An angle exactly halfway between two `Direction`s rounds
+    /// counter-clockwise.
+    #[must_use]
+    pub fn from_angle_nearest(angle: f64) -> Option<Self> {
+        Self::from_angle_nearest_in(angle, Convention::MATH)
+    }
+
+    /// Returns the `Direction` nearest to an angle in radians, measured from `East` in the winding of the
+    /// `Convention`, or `None` if the angle is not finite.
+    ///
+    /// Angles of any magnitude are accepted. An angle exactly halfway between two `Direction`s rounds towards the
+    /// larger angle.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "The quarter count is already integral, and only its value modulo four is used."
+    )]
+    pub fn from_angle_nearest_in(angle: f64, convention: Convention) -> Option<Self> {
+        if !angle.is_finite() {
+            return None;
+        }
+        // `f64::floor` requires `std`, so the `no_std` implementation from `FloatCore` is used instead.
+        let nearest = FloatCore::floor(angle / FRAC_PI_2 + 0.5) as i64;
+        Some(match (nearest.rem_euclid(4), convention.is_clockwise()) {
+            (0, _) => Self::East,
+            (1, false) | (3, true) => Self::North,
+            (2, _) => Self::West,
+            _ => Self::South,
+        })
+    }
+
+    /// Returns the unit step `(dx, dy)` of the `Direction`, with the y-axis pointing North.
+    ///
+    /// This matches `Coord::from(direction)`, for any signed numeric type.
+    #[must_use]
+    pub fn to_vector<T: Signed>(self) -> (T, T) {
+        self.to_vector_in(Convention::MATH)
+    }
+
+    /// Returns the unit step `(dx, dy)` of the `Direction`, with the y-axis of the `Convention`.
+    #[must_use]
+    pub fn to_vector_in<T: Signed>(self, convention: Convention) -> (T, T) {
+        let (dx, dy) = match self {
+            Self::North => (T::zero(), T::one()),
+            Self::East => (T::one(), T::zero()),
+            Self::South => (T::zero(), -T::one()),
+            Self::West => (-T::one(), T::zero()),
+        };
+        (dx, if convention.is_y_down() { -dy } else { dy })
+    }
 }
 
 impl Mul<Transform> for Direction {
@@ -95,16 +173,19 @@ impl Neg for Direction {
 }
 
 impl FromStr for Direction {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_uppercase().as_str() {
-            "N" | "NORTH" => Ok(Self::North),
-            "E" | "EAST" => Ok(Self::East),
-            "S" | "SOUTH" => Ok(Self::South),
-            "W" | "WEST" => Ok(Self::West),
-            _ => Err("Invalid direction, expected one of: N, E, S, W, North, East, South, West"),
-        }
+        [
+            ("N", "NORTH", Self::North),
+            ("E", "EAST", Self::East),
+            ("S", "SOUTH", Self::South),
+            ("W", "WEST", Self::West),
+        ]
+        .into_iter()
+        .find(|(short, long, _)| s.eq_ignore_ascii_case(short) || s.eq_ignore_ascii_case(long))
+        .map(|(_, _, direction)| direction)
+        .ok_or_else(|| ParseError::InvalidDirection(s.into()))
     }
 }
 
@@ -0,0 +1,336 @@
+//! ## `direction_set`
+//!
+//! The `direction_set` module provides the `DirectionSet` struct, a set of `Direction`s used to describe which sides of
+//! a cell are connected.
+
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::FusedIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, MulAssign, Not, Sub, SubAssign},
+    str::FromStr,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ALL_DIRECTIONS, ConversionError, Direction, ParseError, Transform};
+
+/// A set of `Direction`s, stored as a bitset over the `Direction` discriminants.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8", into = "u8"))]
+pub struct DirectionSet {
+    /// Bit `i` is set if the `Direction` with discriminant `i` is a member.
+    bits: u8,
+}
+
+/// An iterator over the members of a `DirectionSet`, in the order of `ALL_DIRECTIONS`.
+#[derive(Clone, Debug)]
+pub struct DirectionSetIter {
+    /// Members which have not yet been yielded.
+    remaining: DirectionSet,
+}
+
+impl DirectionSet {
+    /// The set containing no `Direction`s.
+    pub const EMPTY: Self = Self { bits: 0 };
+    /// The set containing `North` and `South`.
+    pub const VERTICAL: Self = Self { bits: 0b0101 };
+    /// The set containing `East` and `West`.
+    pub const HORIZONTAL: Self = Self { bits: 0b1010 };
+    /// The set containing every `Direction`.
+    pub const ALL: Self = Self { bits: 0b1111 };
+
+    /// Constructs a `DirectionSet` from its bit representation, where bit `i` represents the `Direction` with
+    /// discriminant `i`, or returns `None` if any bit above the lowest four is set.
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        if bits & !Self::ALL.bits == 0 {
+            Some(Self { bits })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the bit representation of the `DirectionSet`.
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.bits
+    }
+
+    /// Constructs a `DirectionSet` containing the given `Direction`s.
+    #[must_use]
+    pub const fn from_directions(directions: &[Direction]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < directions.len() {
+            set = set.with(directions[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// Returns the number of `Direction`s in the set.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns true if the set contains no `Direction`s.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns true if the set contains the given `Direction`.
+    #[must_use]
+    pub const fn contains(self, direction: Direction) -> bool {
+        self.bits & Self::bit(direction) != 0
+    }
+
+    /// Returns a copy of the set with the given `Direction` added.
+    #[must_use]
+    pub const fn with(self, direction: Direction) -> Self {
+        Self {
+            bits: self.bits | Self::bit(direction),
+        }
+    }
+
+    /// Returns a copy of the set with the given `Direction` removed.
+    #[must_use]
+    pub const fn without(self, direction: Direction) -> Self {
+        Self {
+            bits: self.bits & !Self::bit(direction),
+        }
+    }
+
+    /// Adds a `Direction` to the set, returning true if it was not already present.
+    pub const fn insert(&mut self, direction: Direction) -> bool {
+        let inserted = !self.contains(direction);
+        *self = self.with(direction);
+        inserted
+    }
+
+    /// Removes a `Direction` from the set, returning true if it was present.
+    pub const fn remove(&mut self, direction: Direction) -> bool {
+        let removed = self.contains(direction);
+        *self = self.without(direction);
+        removed
+    }
+
+    /// Returns an iterator over the members of the set, in the order of `ALL_DIRECTIONS`.
+    #[must_use]
+    pub const fn iter(self) -> DirectionSetIter {
+        DirectionSetIter { remaining: self }
+    }
+
+    /// Returns the set of `Direction`s in either set.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Returns the set of `Direction`s in both sets.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Returns the set of `Direction`s in `self` but not in `other`.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Returns true if every member of `self` is also a member of `other`.
+    #[must_use]
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.bits & !other.bits == 0
+    }
+
+    /// Returns the bit representing a `Direction`.
+    const fn bit(direction: Direction) -> u8 {
+        1 << direction as u8
+    }
+}
+
+impl Iterator for DirectionSetIter {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bits = self.remaining.bits();
+        if bits == 0 {
+            return None;
+        }
+        let direction = ALL_DIRECTIONS[bits.trailing_zeros() as usize];
+        self.remaining = self.remaining.without(direction);
+        Some(direction)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for DirectionSetIter {}
+
+impl FusedIterator for DirectionSetIter {}
+
+impl IntoIterator for DirectionSet {
+    type Item = Direction;
+    type IntoIter = DirectionSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+impl Extend<Direction> for DirectionSet {
+    fn extend<I: IntoIterator<Item = Direction>>(&mut self, iter: I) {
+        *self = iter.into_iter().fold(*self, Self::with);
+    }
+}
+
+impl From<Direction> for DirectionSet {
+    fn from(direction: Direction) -> Self {
+        Self::EMPTY.with(direction)
+    }
+}
+
+impl TryFrom<u8> for DirectionSet {
+    type Error = ConversionError;
+
+    fn try_from(bits: u8) -> Result<Self, Self::Error> {
+        Self::from_bits(bits).ok_or(ConversionError::InvalidDirectionSetBits(bits))
+    }
+}
+
+impl From<DirectionSet> for u8 {
+    fn from(set: DirectionSet) -> Self {
+        set.bits()
+    }
+}
+
+impl BitOr for DirectionSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for DirectionSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl BitAnd for DirectionSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for DirectionSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitXor for DirectionSet {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self {
+            bits: self.bits ^ rhs.bits,
+        }
+    }
+}
+
+impl BitXorAssign for DirectionSet {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl Sub for DirectionSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for DirectionSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Not for DirectionSet {
+    type Output = Self;
+
+    /// Returns the complement of the set within `ALL`.
+    fn not(self) -> Self::Output {
+        Self::ALL.difference(self)
+    }
+}
+
+impl Mul<Transform> for DirectionSet {
+    type Output = Self;
+
+    /// Applies a `Transform` to every member of the set.
+    fn mul(self, rhs: Transform) -> Self::Output {
+        self.iter().map(|direction| direction * rhs).collect()
+    }
+}
+
+impl MulAssign<Transform> for DirectionSet {
+    fn mul_assign(&mut self, rhs: Transform) {
+        *self = *self * rhs;
+    }
+}
+
+impl FromStr for DirectionSet {
+    type Err = ParseError;
+
+    /// Parses a string of `Direction` symbols in any order and case, such as `"NE"` or `"nesw"`.
+    ///
+    /// Repeated symbols are accepted, and the empty string is the empty set.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.char_indices()
+            .enumerate()
+            .map(|(position, (offset, symbol))| match symbol.to_ascii_uppercase() {
+                'N' => Ok(Direction::North),
+                'E' => Ok(Direction::East),
+                'S' => Ok(Direction::South),
+                'W' => Ok(Direction::West),
+                _ => Err(ParseError::InvalidDirectionSet {
+                    symbol: s[offset..offset + symbol.len_utf8()].into(),
+                    position,
+                }),
+            })
+            .collect()
+    }
+}
+
+impl Display for DirectionSet {
+    /// Writes the symbols of the members in the order of `ALL_DIRECTIONS`, such as `"NESW"`.
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        self.iter().try_for_each(|direction| write!(fmt, "{direction}"))
+    }
+}
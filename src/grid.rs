@@ -0,0 +1,432 @@
+//! ## `grid`
+//!
+//! The `grid` module provides the `Grid` struct, a dense 2D container indexed by `(row, col)` positions, with
+//! neighbour access by `Direction` and `Octant`.
+
+use alloc::vec::Vec;
+use core::{
+    iter::StepBy,
+    ops::{Index, IndexMut, Mul},
+    slice::{Iter, IterMut},
+};
+#[cfg(feature = "array")]
+use ndarray::{Array2, ArrayView2, ArrayViewMut2};
+
+use crate::{ALL_DIRECTIONS, ALL_OCTANTS, Boundary, Coord, Direction, Octant, Transform};
+
+/// A dense 2D grid of cells stored in row-major order.
+///
+/// Cells are addressed by `(row, col)` positions, matching the index space of `Transform::apply_index` and of
+/// 2D arrays. Rows increase downwards, so the `North` neighbour of `(row, col)` is `(row - 1, col)`.
+///
+/// Cells can also be indexed by `Coord`, whose y-axis points North: `x` is the column and `y` counts rows upwards
+/// from the bottom row, so `Coord::new(0, 0)` is the bottom-left cell.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Grid<T> {
+    /// The cells in row-major order.
+    cells: Vec<T>,
+    /// Number of rows.
+    rows: usize,
+    /// Number of columns.
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Constructs a new `Grid` of the given `(rows, cols)` dimensions by calling `f` with the position of each cell,
+    /// in row-major order.
+    #[must_use]
+    pub fn from_fn<F: FnMut((usize, usize)) -> T>((rows, cols): (usize, usize), mut f: F) -> Self {
+        let cells = (0..rows * cols).map(|index| f((index / cols, index % cols))).collect();
+        Self { cells, rows, cols }
+    }
+
+    /// Constructs a new `Grid` of the given `(rows, cols)` dimensions from cells in row-major order, or returns
+    /// `None` if the number of cells does not match the dimensions.
+    #[must_use]
+    pub fn from_vec((rows, cols): (usize, usize), cells: Vec<T>) -> Option<Self> {
+        (cells.len() == rows * cols).then_some(Self { cells, rows, cols })
+    }
+
+    /// Returns the `(rows, cols)` dimensions of the `Grid`.
+    #[must_use]
+    pub const fn dim(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns a new `Grid` of the same dimensions holding the result of `f` for each cell.
+    #[must_use]
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[must_use]
+    pub const fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of cells.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if the `Grid` has no cells.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns true if the position lies within the `Grid`.
+    #[must_use]
+    pub const fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    /// Returns a reference to the cell at the position, or `None` if it lies outside the `Grid`.
+    #[must_use]
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    /// Returns a mutable reference to the cell at the position, or `None` if it lies outside the `Grid`.
+    #[must_use]
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// Returns the `(row, col)` position of a `Coord`, or `None` if it lies outside the `Grid`.
+    ///
+    /// The y-axis of a `Coord` points North, so `Coord::new(x, y)` is the cell at `(rows - 1 - y, x)`.
+    #[must_use]
+    pub fn position(&self, coord: Coord) -> Option<(usize, usize)> {
+        let col = usize::try_from(coord.x).ok()?;
+        let row = self.rows.checked_sub(1)?.checked_sub(usize::try_from(coord.y).ok()?)?;
+        self.contains((row, col)).then_some((row, col))
+    }
+
+    /// Returns the `Coord` of a `(row, col)` position, or `None` if it lies outside the `Grid`.
+    #[must_use]
+    pub fn coord(&self, (row, col): (usize, usize)) -> Option<Coord> {
+        if !self.contains((row, col)) {
+            return None;
+        }
+        Some(Coord::new(i32::try_from(col).ok()?, i32::try_from(self.rows - 1 - row).ok()?))
+    }
+
+    /// Returns the position one step from `pos` in the given `Direction` or `Octant`, or `None` if either position
+    /// lies outside the `Grid`.
+    #[must_use]
+    pub fn step(&self, pos: (usize, usize), direction: impl Into<Octant>) -> Option<(usize, usize)> {
+        self.step_with(pos, direction.into(), Boundary::Bounded).map(|(next, _)| next)
+    }
+
+    /// Steps `pos` one unit in the given `Direction` or `Octant` under the `Boundary` policy, returning the new
+    /// position and heading, or `None` if the step fails or `pos` lies outside the `Grid`.
+    #[must_use]
+    pub fn step_with<D>(&self, pos: (usize, usize), heading: D, boundary: Boundary) -> Option<((usize, usize), D)>
+    where
+        D: Copy + Into<Octant> + Mul<Transform, Output = D>,
+    {
+        boundary.step(self.dim(), pos, heading)
+    }
+
+    /// Returns a reference to the cell one step from `pos` in the given `Direction` or `Octant`, or `None` if either
+    /// position lies outside the `Grid`.
+    #[must_use]
+    pub fn neighbour(&self, pos: (usize, usize), direction: impl Into<Octant>) -> Option<&T> {
+        self.neighbour_with(pos, direction.into(), Boundary::Bounded)
+    }
+
+    /// Returns a reference to the cell one step from `pos` in the given `Direction` or `Octant` under the `Boundary`
+    /// policy, or `None` if the step fails or `pos` lies outside the `Grid`.
+    #[must_use]
+    pub fn neighbour_with(&self, pos: (usize, usize), direction: impl Into<Octant>, boundary: Boundary) -> Option<&T> {
+        self.step_with(pos, direction.into(), boundary).map(|(next, _)| &self[next])
+    }
+
+    /// Returns an iterator over the cells sharing an edge with `pos`, in the order of `ALL_DIRECTIONS`.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (Direction, (usize, usize), &T)> {
+        self.neighbours4_with(pos, Boundary::Bounded)
+    }
+
+    /// Returns an iterator over the cells one step from `pos` in each `Direction` under the `Boundary` policy, in the
+    /// order of `ALL_DIRECTIONS`.
+    ///
+    /// Each item carries the `Direction` stepped in, even if `Reflect` turned the heading.
+    pub fn neighbours4_with(
+        &self,
+        pos: (usize, usize),
+        boundary: Boundary,
+    ) -> impl Iterator<Item = (Direction, (usize, usize), &T)> {
+        ALL_DIRECTIONS.into_iter().filter_map(move |direction| {
+            self.step_with(pos, direction, boundary)
+                .map(|(next, _)| (direction, next, &self[next]))
+        })
+    }
+
+    /// Returns an iterator over the cells sharing an edge or a corner with `pos`, in the order of `ALL_OCTANTS`.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (Octant, (usize, usize), &T)> {
+        self.neighbours8_with(pos, Boundary::Bounded)
+    }
+
+    /// Returns an iterator over the cells one step from `pos` in each `Octant` under the `Boundary` policy, in the
+    /// order of `ALL_OCTANTS`.
+    ///
+    /// Each item carries the `Octant` stepped in, even if `Reflect` turned the heading.
+    pub fn neighbours8_with(
+        &self,
+        pos: (usize, usize),
+        boundary: Boundary,
+    ) -> impl Iterator<Item = (Octant, (usize, usize), &T)> {
+        ALL_OCTANTS.into_iter().filter_map(move |octant| {
+            self.step_with(pos, octant, boundary)
+                .map(|(next, _)| (octant, next, &self[next]))
+        })
+    }
+
+    /// Returns an iterator over the cells in row-major order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Returns a mutable iterator over the cells in row-major order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Returns an iterator over the position and cell of each cell in row-major order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index / cols, index % cols), cell))
+    }
+
+    /// Returns the cells of a row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row lies outside the `Grid`.
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "Row out of bounds");
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns the cells of a row, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row lies outside the `Grid`.
+    #[must_use]
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.rows, "Row out of bounds");
+        &mut self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns an iterator over the cells of a column, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column lies outside the `Grid`.
+    pub fn column(&self, col: usize) -> StepBy<Iter<'_, T>> {
+        assert!(col < self.cols, "Column out of bounds");
+        self.cells[col..].iter().step_by(self.cols)
+    }
+
+    /// Returns an iterator over the rows, from top to bottom.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(move |row| self.row(row))
+    }
+
+    /// Returns an iterator over the columns, from left to right.
+    pub fn iter_columns(&self) -> impl Iterator<Item = StepBy<Iter<'_, T>>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// Returns the cells in row-major order.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns the cells in row-major order, mutably.
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Consumes the `Grid`, returning its cells in row-major order.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// Returns the row-major index of a position, or `None` if it lies outside the `Grid`.
+    const fn index_of(&self, pos: (usize, usize)) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.0 * self.cols + pos.1)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Constructs a new `Grid` of the given `(rows, cols)` dimensions with every cell set to `value`.
+    #[must_use]
+    pub fn new(dim: (usize, usize), value: T) -> Self {
+        Self::from_fn(dim, |_| value.clone())
+    }
+}
+
+#[cfg(feature = "array")]
+impl<T> Grid<T> {
+    /// Returns a zero-copy view of the `Grid` as a 2D array.
+    ///
+    /// # Panics
+    ///
+    /// This function uses `unwrap()` internally but will never panic because the number of cells always matches the
+    /// dimensions of the `Grid`.
+    #[must_use]
+    pub fn view(&self) -> ArrayView2<'_, T> {
+        ArrayView2::from_shape(self.dim(), &self.cells).unwrap()
+    }
+
+    /// Returns a zero-copy mutable view of the `Grid` as a 2D array.
+    ///
+    /// # Panics
+    ///
+    /// This function uses `unwrap()` internally but will never panic because the number of cells always matches the
+    /// dimensions of the `Grid`.
+    #[must_use]
+    pub fn view_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        ArrayViewMut2::from_shape(self.dim(), &mut self.cells).unwrap()
+    }
+
+    /// Consumes the `Grid`, returning it as a 2D array without copying its cells.
+    ///
+    /// # Panics
+    ///
+    /// This function uses `unwrap()` internally but will never panic because the number of cells always matches the
+    /// dimensions of the `Grid`.
+    #[must_use]
+    pub fn into_array(self) -> Array2<T> {
+        Array2::from_shape_vec(self.dim(), self.cells).unwrap()
+    }
+}
+
+#[cfg(feature = "array")]
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(arr: Array2<T>) -> Self {
+        let (rows, cols) = arr.dim();
+        Self {
+            cells: arr.into_iter().collect(),
+            rows,
+            cols,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// Returns the cell at the position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position lies outside the `Grid`.
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos).expect("Index out of bounds")
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    /// Returns the cell at the `Coord`, with the y-axis pointing North.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Coord` lies outside the `Grid`.
+    fn index(&self, coord: Coord) -> &Self::Output {
+        &self[self.position(coord).expect("Index out of bounds")]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    /// Returns the cell at the position, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position lies outside the `Grid`.
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(pos).expect("Index out of bounds")
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    /// Returns the cell at the `Coord`, mutably, with the y-axis pointing North.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Coord` lies outside the `Grid`.
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let pos = self.position(coord).expect("Index out of bounds");
+        &mut self[pos]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Clone> Mul<Transform> for &Grid<T> {
+    type Output = Grid<T>;
+
+    /// Returns a copy of the `Grid` with the `Transform` applied, so that the cell at `pos` moves to
+    /// `rhs.apply_index(self.dim(), pos)`.
+    ///
+    /// A neighbour in `Direction` `d` lies in `d * rhs.index_heading()` afterwards, which is `d * rhs` except for
+    /// `FlipDiagonal` and `FlipAntiDiagonal`: those transpose the cells like an array, and so map headings as each
+    /// other does.
+    fn mul(self, rhs: Transform) -> Self::Output {
+        let (cells, cols, rows) = rhs.apply_buffer(&self.cells, self.cols, self.rows);
+        Grid { cells, rows, cols }
+    }
+}
+
+impl<T: Clone> Mul<Transform> for Grid<T> {
+    type Output = Self;
+
+    /// Returns the `Grid` with the `Transform` applied.
+    fn mul(self, rhs: Transform) -> Self::Output {
+        &self * rhs
+    }
+}
@@ -0,0 +1,111 @@
+//! ## `imaging`
+//!
+//! The `imaging` module applies `Transform`s to images from the `image` crate, mapping each one onto the equivalent
+//! operations of `image::imageops`.
+
+use alloc::vec::Vec;
+use core::ops::{Deref, Mul};
+use image::{DynamicImage, ImageBuffer, Pixel, imageops, metadata::Orientation};
+
+use crate::Transform;
+
+impl From<Transform> for Orientation {
+    /// Returns the `Orientation` which transforms an image identically to the `Transform`.
+    fn from(transform: Transform) -> Self {
+        match transform {
+            Transform::Identity => Self::NoTransforms,
+            Transform::Rotate90 => Self::Rotate90,
+            Transform::Rotate180 => Self::Rotate180,
+            Transform::Rotate270 => Self::Rotate270,
+            Transform::FlipHorizontal => Self::FlipHorizontal,
+            Transform::FlipDiagonal => Self::Rotate90FlipH,
+            Transform::FlipVertical => Self::FlipVertical,
+            Transform::FlipAntiDiagonal => Self::Rotate270FlipH,
+        }
+    }
+}
+
+impl From<Orientation> for Transform {
+    /// Returns the `Transform` which transforms an image identically to the `Orientation`.
+    fn from(orientation: Orientation) -> Self {
+        match orientation {
+            Orientation::NoTransforms => Self::Identity,
+            Orientation::Rotate90 => Self::Rotate90,
+            Orientation::Rotate180 => Self::Rotate180,
+            Orientation::Rotate270 => Self::Rotate270,
+            Orientation::FlipHorizontal => Self::FlipHorizontal,
+            Orientation::Rotate90FlipH => Self::FlipDiagonal,
+            Orientation::FlipVertical => Self::FlipVertical,
+            Orientation::Rotate270FlipH => Self::FlipAntiDiagonal,
+        }
+    }
+}
+
+impl<P, C> Mul<&ImageBuffer<P, C>> for Transform
+where
+    P: Pixel + 'static,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    type Output = ImageBuffer<P, Vec<P::Subpixel>>;
+
+    /// Returns a copy of the image with the `Transform` applied, matching `self * &arr` for an array of its pixels
+    /// indexed `(y, x)`.
+    ///
+    /// The diagonal flips are performed as a quarter turn followed by an in-place flip, so every `Transform`
+    /// allocates a single output image.
+    fn mul(self, rhs: &ImageBuffer<P, C>) -> Self::Output {
+        match self {
+            Self::Identity => ImageBuffer::from_fn(rhs.width(), rhs.height(), |x, y| *rhs.get_pixel(x, y)),
+            Self::Rotate90 => imageops::rotate90(rhs),
+            Self::Rotate180 => imageops::rotate180(rhs),
+            Self::Rotate270 => imageops::rotate270(rhs),
+            Self::FlipHorizontal => imageops::flip_horizontal(rhs),
+            Self::FlipDiagonal => {
+                let mut image = imageops::rotate90(rhs);
+                imageops::flip_horizontal_in_place(&mut image);
+                image
+            }
+            Self::FlipVertical => imageops::flip_vertical(rhs),
+            Self::FlipAntiDiagonal => {
+                let mut image = imageops::rotate90(rhs);
+                imageops::flip_vertical_in_place(&mut image);
+                image
+            }
+        }
+    }
+}
+
+impl Mul<&DynamicImage> for Transform {
+    type Output = DynamicImage;
+
+    /// Returns a copy of the image with the `Transform` applied, preserving its pixel format.
+    fn mul(self, rhs: &DynamicImage) -> Self::Output {
+        match self {
+            Self::Identity => rhs.clone(),
+            Self::Rotate90 => rhs.rotate90(),
+            Self::Rotate180 => rhs.rotate180(),
+            Self::Rotate270 => rhs.rotate270(),
+            Self::FlipHorizontal => rhs.fliph(),
+            Self::FlipDiagonal => {
+                let mut image = rhs.rotate90();
+                image.apply_orientation(Orientation::FlipHorizontal);
+                image
+            }
+            Self::FlipVertical => rhs.flipv(),
+            Self::FlipAntiDiagonal => {
+                let mut image = rhs.rotate90();
+                image.apply_orientation(Orientation::FlipVertical);
+                image
+            }
+        }
+    }
+}
+
+impl Transform {
+    /// Applies the `Transform` to a `DynamicImage` in place.
+    ///
+    /// Flips and half turns are performed without allocating; quarter turns and diagonal flips replace the image.
+    pub fn apply_image(self, image: &mut DynamicImage) {
+        image.apply_orientation(self.into());
+    }
+}
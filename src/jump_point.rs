@@ -0,0 +1,604 @@
+//! ## `jump_point`
+//!
+//! The `jump_point` module provides Jump Point Search over uniform-cost `Grid`s, which finds the same shortest paths
+//! as A* while skipping over the symmetric runs of open cells between jump points. A `JumpTable` precomputes the
+//! jump distances of every cell (JPS+), so that repeated searches over the same map avoid scanning altogether.
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde")]
+use crate::ConversionError;
+use crate::{ALL_OCTANTS, Convention, Coord, Grid, Heuristic, Move, Octant, Transform, pathfinding::Open};
+
+/// Precomputed jump distances of a uniform-cost map, for repeated Jump Point Searches (JPS+).
+///
+/// For every cell and every `Move`, the table stores how far a search may travel before reaching the next jump point
+/// or an obstacle. Build one with `Grid::jump_table`; it must be rebuilt whenever the passability of the map changes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "JumpTableParts", bound(deserialize = "M: Move")))]
+pub struct JumpTable<M> {
+    /// Number of rows of the map.
+    rows: usize,
+    /// Number of columns of the map.
+    cols: usize,
+    /// Passability of each cell in row-major order, surrounded by a border of impassable cells.
+    open: Vec<bool>,
+    /// Jump distance of each cell and move: positive to reach a jump point, otherwise minus the open run length.
+    distances: Vec<i32>,
+    /// The kind of move the distances were computed for.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    moves: PhantomData<M>,
+}
+
+/// The serialized fields of a `JumpTable`, which are checked for consistency before a table is built from them.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "JumpTable")]
+struct JumpTableParts {
+    /// Number of rows of the map.
+    rows: usize,
+    /// Number of columns of the map.
+    cols: usize,
+    /// Passability of each cell in row-major order, surrounded by a border of impassable cells.
+    open: Vec<bool>,
+    /// Jump distance of each cell and move.
+    distances: Vec<i32>,
+}
+
+/// Passability of a rectangular map surrounded by a border of impassable cells, with the jump rules of Jump Point
+/// Search.
+///
+/// Cells are addressed by their index within the padded map, so that a step in any `Octant` adds a fixed offset and
+/// can never leave it. Diagonal moves may not cut the corner of an impassable cell, matching `Grid::astar`.
+#[derive(Clone, Copy)]
+struct Scan<'a> {
+    /// Number of cells in a padded row, two more than the number of columns of the map.
+    width: usize,
+    /// Whether diagonal moves are allowed.
+    diagonal: bool,
+    /// Index offset of a single step in each `Octant`.
+    deltas: [isize; 8],
+    /// Passability of each cell of the padded map in row-major order.
+    open: &'a [bool],
+}
+
+/// An online Jump Point Search towards a goal, remembering which horizontal jumps succeed without diagonal moves.
+struct Online<'a> {
+    /// Passability of the map.
+    scan: Scan<'a>,
+    /// Index of the goal within the padded map.
+    goal: usize,
+    /// Two bits for each horizontal `Octant` of each cell: whether a jump from the cell has been made, and whether it
+    /// reached a jump point. Empty when diagonal moves are allowed.
+    horizontal: Vec<u8>,
+}
+
+/// A jump from an expanded position: the position reached, the `Octant` travelled and the number of steps taken.
+type Jump = ((usize, usize), Octant, usize);
+
+/// Returns the `(row, col)` offset of a single step in the `Octant`, with rows increasing downwards.
+fn offset(octant: Octant) -> (isize, isize) {
+    let (dx, dy) = octant.to_vector_in(Convention::SCREEN);
+    (dy, dx)
+}
+
+/// Returns the two cardinal components of a diagonal `Octant`.
+fn components(octant: Octant) -> impl Iterator<Item = Octant> {
+    let Coord { x, y } = Coord::from(octant);
+    [Coord::new(x, 0), Coord::new(0, y)].into_iter().filter_map(Coord::octant)
+}
+
+/// Returns the two cardinal directions perpendicular to a cardinal `Octant`.
+fn sides(octant: Octant) -> [Octant; 2] {
+    [octant * Transform::Rotate90, octant * Transform::Rotate270]
+}
+
+/// Returns true if `M` includes diagonal moves.
+fn is_diagonal<M: Move>() -> bool {
+    M::ALL.iter().any(|&step| step.into().is_diagonal())
+}
+
+/// Returns the index of the `Octant` within `M::ALL`.
+fn slot<M: Move>(octant: Octant) -> Option<usize> {
+    M::ALL.iter().position(|&step| step.into() == octant)
+}
+
+/// Returns the passability of a map of `(rows, cols)` cells in row-major order, surrounded by a border of impassable
+/// cells.
+fn pad((rows, cols): (usize, usize), cells: impl Iterator<Item = bool>) -> Vec<bool> {
+    let width = cols + 2;
+    let mut open = vec![false; (rows + 2) * width];
+    for (index, cell) in cells.enumerate() {
+        open[(index / cols + 1) * width + index % cols + 1] = cell;
+    }
+    open
+}
+
+/// Returns the number of steps of size `delta` from `from` to `to` along one axis, or `None` if `to` cannot be reached.
+const fn steps_to(from: usize, to: usize, delta: isize) -> Option<usize> {
+    match delta {
+        1.. => to.checked_sub(from),
+        0 => {
+            if from == to {
+                Some(0)
+            } else {
+                None
+            }
+        }
+        _ => from.checked_sub(to),
+    }
+}
+
+/// Returns the position `steps` steps of size `delta` from `from` along one axis.
+const fn travel(from: usize, delta: isize, steps: usize) -> usize {
+    match delta {
+        1.. => from + steps,
+        0 => from,
+        _ => from - steps,
+    }
+}
+
+/// Returns true if `steps` steps of size `delta` from `from` stay within an axis of `len` cells.
+#[cfg(feature = "serde")]
+const fn stays_within(from: usize, len: usize, delta: isize, steps: usize) -> bool {
+    match delta {
+        1.. => steps < len - from,
+        0 => true,
+        _ => steps <= from,
+    }
+}
+
+/// Returns the `Octant` and number of steps of a straight or diagonal jump from `from` to `to`.
+fn heading(from: (usize, usize), to: (usize, usize)) -> Option<(Octant, usize)> {
+    let sign = |from: usize, to: usize| i32::from(to > from) - i32::from(to < from);
+    let octant = Coord::new(sign(from.1, to.1), sign(from.0, to.0)).octant_in(Convention::SCREEN)?;
+    Some((octant, from.0.abs_diff(to.0).max(from.1.abs_diff(to.1))))
+}
+
+/// Runs A* between jump points, with `successors` listing the jumps from a position reached in a heading.
+#[allow(
+    clippy::cast_precision_loss,
+    reason = "Jump lengths are far below the range in which `f64` loses integer precision."
+)]
+fn search<M, S>(
+    (rows, cols): (usize, usize),
+    start: (usize, usize),
+    goal: (usize, usize),
+    mut successors: S,
+) -> Option<(Vec<M>, f64)>
+where
+    M: Move,
+    S: FnMut((usize, usize), Option<Octant>, &mut Vec<Jump>),
+{
+    let heuristic = if is_diagonal::<M>() {
+        Heuristic::Octile
+    } else {
+        Heuristic::Manhattan
+    };
+    // Both tables start zeroed, which large allocations provide without touching memory, so that a search only pays
+    // for the cells it reaches. A cell other than the start has been reached once it records the state before it,
+    // offset by one, and its best cost so far is then stored.
+    let mut best = vec![0.0; rows * cols];
+    let mut parents = vec![0_usize; rows * cols];
+    let mut open = BinaryHeap::new();
+    let mut jumps = Vec::new();
+
+    let start_state = start.0 * cols + start.1;
+    open.push(Open {
+        estimate: heuristic.estimate(start, goal),
+        cost: 0.0,
+        order: 0,
+        state: start_state,
+    });
+    let mut order = 1;
+
+    while let Some(Open { cost: so_far, state, .. }) = open.pop() {
+        if so_far > best[state] {
+            continue;
+        }
+        let pos = (state / cols, state % cols);
+        if pos == goal {
+            let mut moves = Vec::new();
+            let mut current = pos;
+            while let Some(previous) = parents[current.0 * cols + current.1].checked_sub(1) {
+                let previous = (previous / cols, previous % cols);
+                let (octant, steps) = heading(previous, current)?;
+                let step = M::ALL[slot::<M>(octant)?];
+                moves.extend(core::iter::repeat_n(step, steps));
+                current = previous;
+            }
+            moves.reverse();
+            return Some((moves, so_far));
+        }
+
+        jumps.clear();
+        let arrival = parents[state]
+            .checked_sub(1)
+            .and_then(|previous| heading((previous / cols, previous % cols), pos));
+        successors(pos, arrival.map(|(octant, _)| octant), &mut jumps);
+        for &(next, octant, steps) in &jumps {
+            let next_cost = so_far + steps as f64 * octant.length();
+            let next_state = next.0 * cols + next.1;
+            if next_state != start_state && (parents[next_state] == 0 || next_cost < best[next_state]) {
+                best[next_state] = next_cost;
+                parents[next_state] = state + 1;
+                open.push(Open {
+                    estimate: next_cost + heuristic.estimate(next, goal),
+                    cost: next_cost,
+                    order,
+                    state: next_state,
+                });
+                order += 1;
+            }
+        }
+    }
+    None
+}
+
+impl<'a> Scan<'a> {
+    /// Returns the jump rules of a padded map with the given number of columns.
+    fn new(cols: usize, diagonal: bool, open: &'a [bool]) -> Self {
+        let width = cols + 2;
+        let deltas = ALL_OCTANTS.map(|octant| {
+            let (dr, dc) = offset(octant);
+            dr * width.cast_signed() + dc
+        });
+        Self {
+            width,
+            diagonal,
+            deltas,
+            open,
+        }
+    }
+
+    /// Returns the index within the padded map of the position `(row, col)` of the map.
+    const fn index(&self, (row, col): (usize, usize)) -> usize {
+        (row + 1) * self.width + col + 1
+    }
+
+    /// Returns the position `(row, col)` within the map of an index of the padded map.
+    const fn position(&self, index: usize) -> (usize, usize) {
+        (index / self.width - 1, index % self.width - 1)
+    }
+
+    /// Returns the index one step from `index` in the `Octant`.
+    const fn step(&self, index: usize, octant: Octant) -> usize {
+        index.wrapping_add_signed(self.deltas[octant as usize])
+    }
+
+    /// Returns true if a step from `index` in the `Octant` enters a passable cell without cutting a corner.
+    fn can_move(&self, index: usize, octant: Octant) -> bool {
+        self.open[self.step(index, octant)]
+            && (!octant.is_diagonal() || components(octant).all(|side| self.open[self.step(index, side)]))
+    }
+
+    /// Returns true if a cardinal step into `index` in the `Octant` reveals the neighbour on the given `side`, which
+    /// is then only reached optimally through `index`.
+    fn is_forced_on(&self, index: usize, octant: Octant, side: Octant) -> bool {
+        let neighbour = self.step(index, side);
+        self.open[neighbour] && !self.open[self.step(neighbour, -octant)]
+    }
+
+    /// Returns true if a cardinal step into `index` in the `Octant` reveals a side neighbour which is only reached
+    /// optimally through `index`.
+    fn is_forced(&self, index: usize, octant: Octant) -> bool {
+        sides(octant).into_iter().any(|side| self.is_forced_on(index, octant, side))
+    }
+
+    /// Fills `directions` with the moves worth exploring from the jump point at `index`, reached by travelling in
+    /// `heading`, or with every move of `M` from the start of a search.
+    ///
+    /// Neighbours which can be reached at least as cheaply without passing through `index` are pruned.
+    fn prune<M: Move>(&self, index: usize, heading: Option<Octant>, directions: &mut Vec<Octant>) {
+        directions.clear();
+        let Some(heading) = heading else {
+            directions.extend(M::ALL.iter().map(|&step| step.into()));
+            return;
+        };
+        if heading.is_diagonal() {
+            directions.extend(components(heading));
+        } else if !self.diagonal && offset(heading).1 == 0 {
+            // Vertical jump points may have been found by a horizontal jump on either side.
+            directions.extend(sides(heading));
+        } else {
+            for side in sides(heading) {
+                if self.is_forced_on(index, heading, side) {
+                    directions.push(side);
+                    if self.diagonal {
+                        directions.extend((Coord::from(heading) + Coord::from(side)).octant());
+                    }
+                }
+            }
+        }
+        directions.push(heading);
+    }
+}
+
+impl<'a> Online<'a> {
+    /// Returns a search of the map towards the `goal` position.
+    fn new(scan: Scan<'a>, goal: (usize, usize)) -> Self {
+        Self {
+            scan,
+            goal: scan.index(goal),
+            horizontal: if scan.diagonal { Vec::new() } else { vec![0; scan.open.len()] },
+        }
+    }
+
+    /// Returns true if `index`, entered in the `Octant`, is a jump point.
+    fn is_jump_point(&mut self, index: usize, octant: Octant) -> bool {
+        if octant.is_diagonal() {
+            components(octant).any(|side| self.jump(index, side).is_some())
+        } else {
+            self.scan.is_forced(index, octant)
+                || (!self.scan.diagonal
+                    && offset(octant).1 == 0
+                    && (self.reaches(index, Octant::East) || self.reaches(index, Octant::West)))
+        }
+    }
+
+    /// Returns true if a horizontal jump from `index` in the `Octant` reaches a jump point, without diagonal moves.
+    ///
+    /// Vertical jumps try both horizontal jumps from every cell they pass, so the answer is remembered: a horizontal
+    /// jump stops only at the goal or at a forced neighbour, and every cell it passes reaches the same jump point or
+    /// obstacle.
+    fn reaches(&mut self, index: usize, octant: Octant) -> bool {
+        let shift = if octant == Octant::East { 0 } else { 2 };
+        let known = self.horizontal[index] >> shift;
+        if known & 1 != 0 {
+            return known & 2 != 0;
+        }
+        let end = self.jump(index, octant).map(|(end, _)| end);
+        let bits = (1 | u8::from(end.is_some()) << 1) << shift;
+        let mut cell = index;
+        loop {
+            self.horizontal[cell] |= bits;
+            cell = self.scan.step(cell, octant);
+            if Some(cell) == end || !self.scan.open[cell] {
+                return end.is_some();
+            }
+        }
+    }
+
+    /// Travels from `index` in the `Octant` until reaching the goal or a jump point, returning the index reached and
+    /// the number of steps taken, or `None` if an obstacle is reached first.
+    fn jump(&mut self, mut index: usize, octant: Octant) -> Option<(usize, usize)> {
+        let mut steps = 0;
+        loop {
+            if !self.scan.can_move(index, octant) {
+                return None;
+            }
+            index = self.scan.step(index, octant);
+            steps += 1;
+            if index == self.goal || self.is_jump_point(index, octant) {
+                return Some((index, steps));
+            }
+        }
+    }
+}
+
+impl<M: Move> JumpTable<M> {
+    /// Precomputes the jump distances of a map of `(rows, cols)` cells with the given padded passability.
+    fn new((rows, cols): (usize, usize), open: Vec<bool>) -> Self {
+        let moves = M::ALL.len();
+        let diagonal = is_diagonal::<M>();
+        let mut distances = vec![0; rows * cols * moves];
+        let scan = Scan::new(cols, diagonal, &open);
+
+        // Cardinal jumps stop where a perpendicular jump would succeed when diagonal moves are allowed, and
+        // vertical jumps stop where a horizontal jump would succeed otherwise, so those distances are found last.
+        let mut order: Vec<(usize, Octant)> = M::ALL.iter().map(|&step| step.into()).enumerate().collect();
+        order.sort_by_key(|&(_, octant)| (octant.is_diagonal(), !diagonal && offset(octant).1 == 0));
+
+        for (index, octant) in order {
+            let (dr, dc) = offset(octant);
+            let sides: Vec<usize> = if octant.is_diagonal() {
+                components(octant).filter_map(slot::<M>).collect()
+            } else if !diagonal && dc == 0 {
+                [Octant::East, Octant::West].into_iter().filter_map(slot::<M>).collect()
+            } else {
+                Vec::new()
+            };
+            // Each distance depends on that of the next cell along the move, so cells are visited from the far end.
+            for i in 0..rows {
+                let row = if dr > 0 { rows - 1 - i } else { i };
+                for j in 0..cols {
+                    let col = if dc > 0 { cols - 1 - j } else { j };
+                    let cell = scan.index((row, col));
+                    let distance = if scan.can_move(cell, octant) {
+                        let base = (travel(row, dr, 1) * cols + travel(col, dc, 1)) * moves;
+                        if sides.iter().any(|&side| distances[base + side] > 0)
+                            || (!octant.is_diagonal() && scan.is_forced(scan.step(cell, octant), octant))
+                        {
+                            1
+                        } else {
+                            let after = distances[base + index];
+                            if after > 0 { after + 1 } else { after - 1 }
+                        }
+                    } else {
+                        0
+                    };
+                    distances[(row * cols + col) * moves + index] = distance;
+                }
+            }
+        }
+
+        Self {
+            rows,
+            cols,
+            open,
+            distances,
+            moves: PhantomData,
+        }
+    }
+
+    /// Returns the dimensions `(rows, cols)` of the map.
+    #[must_use]
+    pub const fn dim(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns true if the cell at `pos` exists and is passable.
+    #[must_use]
+    pub fn is_passable(&self, (row, col): (usize, usize)) -> bool {
+        row < self.rows && col < self.cols && self.open[(row + 1) * (self.cols + 2) + col + 1]
+    }
+
+    /// Returns the jump point reached by travelling from `pos` in the direction of `step`, and the number of steps
+    /// taken, or `None` if an obstacle or the edge of the map is reached first.
+    ///
+    /// Jump points do not depend on the goal of a search, which is handled separately by `path`.
+    #[must_use]
+    pub fn jump(&self, pos: (usize, usize), step: M) -> Option<((usize, usize), usize)> {
+        if pos.0 >= self.rows || pos.1 >= self.cols {
+            return None;
+        }
+        let octant = step.into();
+        let distance = self.distance(pos, octant)?;
+        let steps = usize::try_from(distance).ok().filter(|&steps| steps > 0)?;
+        let (dr, dc) = offset(octant);
+        Some(((travel(pos.0, dr, steps), travel(pos.1, dc, steps)), steps))
+    }
+
+    /// Returns the moves and total length of a shortest path from `start` to `goal`, or `None` if the `goal` is
+    /// unreachable or either position lies outside the map.
+    ///
+    /// Paths have the same length as those of `Grid::jps` and of `Grid::astar` with a unit cost for passable cells.
+    #[must_use]
+    pub fn path(&self, start: (usize, usize), goal: (usize, usize)) -> Option<(Vec<M>, f64)> {
+        if start.0 >= self.rows || start.1 >= self.cols || goal.0 >= self.rows || goal.1 >= self.cols {
+            return None;
+        }
+        let scan = self.scan();
+        let diagonal = scan.diagonal;
+        let mut directions = Vec::new();
+        search(self.dim(), start, goal, |pos, heading, jumps| {
+            scan.prune::<M>(scan.index(pos), heading, &mut directions);
+            for &octant in &directions {
+                let Some(distance) = self.distance(pos, octant) else {
+                    continue;
+                };
+                let reach = distance.unsigned_abs() as usize;
+                let (dr, dc) = offset(octant);
+                let at = |steps| ((travel(pos.0, dr, steps), travel(pos.1, dc, steps)), octant, steps);
+                // The table knows nothing of the goal, so the search also stops wherever the goal could be reached
+                // by a jump along this move or, as for an online search, by a perpendicular jump from it.
+                if let (Some(rows), Some(cols)) = (steps_to(pos.0, goal.0, dr), steps_to(pos.1, goal.1, dc)) {
+                    let steps = if octant.is_diagonal() {
+                        rows.min(cols)
+                    } else {
+                        rows.max(cols)
+                    };
+                    if (1..=reach).contains(&steps) {
+                        jumps.push(at(steps));
+                    }
+                } else if !diagonal
+                    && dc == 0
+                    && let Some(steps) = steps_to(pos.0, goal.0, dr).filter(|steps| (1..=reach).contains(steps))
+                {
+                    jumps.push(at(steps));
+                }
+                if distance > 0 {
+                    jumps.push(at(reach));
+                }
+            }
+        })
+    }
+
+    /// Returns the passability of the map, with the jump rules of `M`.
+    fn scan(&self) -> Scan<'_> {
+        Scan::new(self.cols, is_diagonal::<M>(), &self.open)
+    }
+
+    /// Returns the stored jump distance of `pos` in the `Octant`, or `None` if `M` has no such move.
+    fn distance(&self, (row, col): (usize, usize), octant: Octant) -> Option<i32> {
+        Some(self.distances[(row * self.cols + col) * M::ALL.len() + slot::<M>(octant)?])
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<M: Move> TryFrom<JumpTableParts> for JumpTable<M> {
+    type Error = ConversionError;
+
+    /// Rejects tables whose lengths do not match their dimensions, whose border is passable, or whose distances
+    /// travel off the map, any of which would make searches index out of bounds.
+    fn try_from(parts: JumpTableParts) -> Result<Self, Self::Error> {
+        let JumpTableParts {
+            rows,
+            cols,
+            open,
+            distances,
+        } = parts;
+        let moves = M::ALL.len();
+        let padded = (rows + 2).checked_mul(cols + 2);
+        let cells = rows.checked_mul(cols).and_then(|cells| cells.checked_mul(moves));
+        if padded != Some(open.len()) || cells != Some(distances.len()) {
+            return Err(ConversionError::InconsistentJumpTable);
+        }
+        let width = cols + 2;
+        let border = open[..width].iter().chain(&open[open.len() - width..]).any(|&cell| cell)
+            || (1..=rows).any(|row| open[row * width] || open[row * width + cols + 1]);
+        let in_reach = (0..rows * cols).all(|cell| {
+            let (row, col) = (cell / cols, cell % cols);
+            M::ALL.iter().enumerate().all(|(index, &step)| {
+                let (dr, dc) = offset(step.into());
+                let steps = distances[cell * moves + index].unsigned_abs() as usize;
+                stays_within(row, rows, dr, steps) && stays_within(col, cols, dc, steps)
+            })
+        });
+        if border || !in_reach {
+            return Err(ConversionError::InconsistentJumpTable);
+        }
+        Ok(Self {
+            rows,
+            cols,
+            open,
+            distances,
+            moves: PhantomData,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the moves and total length of a shortest path from `start` to `goal` found by Jump Point Search, or
+    /// `None` if the `goal` is unreachable or either position lies outside the `Grid`.
+    ///
+    /// Every `passable` cell costs the same to enter, and as for `astar`, diagonal moves may not cut the corner of an
+    /// impassable cell. Paths have the same length as those of `astar` with a unit cost for passable cells, but far
+    /// fewer positions are expanded on open maps. Use a `JumpTable` to repeat searches over the same map.
+    pub fn jps<M, P>(&self, start: (usize, usize), goal: (usize, usize), mut passable: P) -> Option<(Vec<M>, f64)>
+    where
+        M: Move,
+        P: FnMut(&T) -> bool,
+    {
+        if !self.contains(start) || !self.contains(goal) {
+            return None;
+        }
+        // Jumps revisit the same cells many times, so passability is evaluated once up front.
+        let open = pad(self.dim(), self.iter().map(&mut passable));
+        let mut online = Online::new(Scan::new(self.cols(), is_diagonal::<M>(), &open), goal);
+        let mut directions = Vec::new();
+        search(self.dim(), start, goal, |pos, heading, jumps| {
+            let index = online.scan.index(pos);
+            online.scan.prune::<M>(index, heading, &mut directions);
+            for &octant in &directions {
+                if let Some((next, steps)) = online.jump(index, octant) {
+                    jumps.push((online.scan.position(next), octant, steps));
+                }
+            }
+        })
+    }
+
+    /// Precomputes the jump distances of every cell for repeated Jump Point Searches over this `Grid` (JPS+), with
+    /// the same rules as `jps`.
+    pub fn jump_table<M, P>(&self, passable: P) -> JumpTable<M>
+    where
+        M: Move,
+        P: FnMut(&T) -> bool,
+    {
+        JumpTable::new(self.dim(), pad(self.dim(), self.iter().map(passable)))
+    }
+}
@@ -1,7 +1,11 @@
 //! # `Nav`
 //!
-//! `Nav` is a very simple utility library providing types for working with cardinal directions and transformations.
+//! `Nav` is a very simple utility library providing types for working with cardinal directions, octants and transformations.
+//!
+//! The crate is `no_std`. Types which allocate, such as `TransformWord`, require the `alloc` feature, which is enabled
+//! by the default `std` feature.
 
+#![no_std]
 #![deny(absolute_paths_not_starting_with_crate)]
 #![deny(ambiguous_negative_literals)]
 #![deny(dead_code)]
@@ -75,8 +79,62 @@
 )]
 #![allow(clippy::module_inception, reason = "Modules publicly re-export types of the same name.")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// Benchmarks and integration tests are the only users of `criterion` and `serde_test`, which the library's own test
+// target also links.
+#[cfg(test)]
+use criterion as _;
+#[cfg(test)]
+use serde_test as _;
+
+mod boundary;
+mod convention;
+mod conversion_error;
+mod coord;
 mod direction;
+mod direction_set;
+#[cfg(feature = "alloc")]
+mod grid;
+#[cfg(feature = "image")]
+mod imaging;
+#[cfg(feature = "alloc")]
+mod jump_point;
+mod octant;
+mod parse_error;
+#[cfg(feature = "alloc")]
+mod pathfinding;
+#[cfg(feature = "alloc")]
+mod search;
+#[cfg(feature = "array")]
+mod symmetry;
 mod transform;
+mod transform_set;
+#[cfg(feature = "alloc")]
+mod transform_word;
+mod turn;
 
+pub use boundary::Boundary;
+pub use convention::{Convention, Winding, YAxis};
+pub use conversion_error::ConversionError;
+pub use coord::Coord;
 pub use direction::{ALL_DIRECTIONS, Direction};
+pub use direction_set::{DirectionSet, DirectionSetIter};
+#[cfg(feature = "alloc")]
+pub use grid::Grid;
+#[cfg(feature = "alloc")]
+pub use jump_point::JumpTable;
+pub use octant::{ALL_OCTANTS, Octant};
+pub use parse_error::{MAX_REJECTED_INPUT_LEN, ParseError, RejectedInput};
+#[cfg(feature = "alloc")]
+pub use pathfinding::{Heuristic, Move};
+#[cfg(feature = "alloc")]
+pub use search::{Connectivity, DistanceField};
+#[cfg(feature = "array")]
+pub use symmetry::Orbit;
 pub use transform::{ALL_TRANSFORMS, Transform};
+pub use transform_set::{ALL_SUBGROUPS, TransformSet, TransformSetIter};
+#[cfg(feature = "alloc")]
+pub use transform_word::TransformWord;
+pub use turn::{ALL_TURNS, Turn};
@@ -0,0 +1,199 @@
+//! ## `octant`
+//!
+//! The `octant` module provides the `Octant` enum, which represents the eight compass directions:
+//! the four cardinal directions and the four diagonals between them.
+
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Mul, MulAssign, Neg},
+    str::FromStr,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use num_traits::Signed;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Convention, ConversionError, Direction, ParseError, Transform};
+
+/// All octants in their order of definition.
+pub const ALL_OCTANTS: [Octant; 8] = [
+    Octant::North,
+    Octant::NorthEast,
+    Octant::East,
+    Octant::SouthEast,
+    Octant::South,
+    Octant::SouthWest,
+    Octant::West,
+    Octant::NorthWest,
+];
+
+/// The eight compass directions, ordered clockwise starting from North.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum Octant {
+    /// Upwards
+    North = 0,
+    /// Upwards and rightwards
+    NorthEast = 1,
+    /// Rightwards
+    East = 2,
+    /// Downwards and rightwards
+    SouthEast = 3,
+    /// Downwards
+    South = 4,
+    /// Downwards and leftwards
+    SouthWest = 5,
+    /// Leftwards
+    West = 6,
+    /// Upwards and leftwards
+    NorthWest = 7,
+}
+
+impl Octant {
+    /// Returns true if the `Octant` is one of the four cardinal directions.
+    #[must_use]
+    pub const fn is_cardinal(self) -> bool {
+        matches!(self, Self::North | Self::East | Self::South | Self::West)
+    }
+
+    /// Returns true if the `Octant` lies between two cardinal directions.
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        matches!(self, Self::NorthEast | Self::SouthEast | Self::SouthWest | Self::NorthWest)
+    }
+
+    /// Returns the step `(dx, dy)` of the `Octant`, with the y-axis pointing North.
+    ///
+    /// Both components of a diagonal step are non-zero. This matches `Coord::from(octant)`, for any signed numeric
+    /// type.
+    #[must_use]
+    pub fn to_vector<T: Signed>(self) -> (T, T) {
+        self.to_vector_in(Convention::MATH)
+    }
+
+    /// Returns the step `(dx, dy)` of the `Octant`, with the y-axis of the `Convention`.
+    #[must_use]
+    pub fn to_vector_in<T: Signed>(self, convention: Convention) -> (T, T) {
+        let (dx, dy) = match self {
+            Self::North => (T::zero(), T::one()),
+            Self::NorthEast => (T::one(), T::one()),
+            Self::East => (T::one(), T::zero()),
+            Self::SouthEast => (T::one(), -T::one()),
+            Self::South => (T::zero(), -T::one()),
+            Self::SouthWest => (-T::one(), -T::one()),
+            Self::West => (-T::one(), T::zero()),
+            Self::NorthWest => (-T::one(), T::one()),
+        };
+        (dx, if convention.is_y_down() { -dy } else { dy })
+    }
+}
+
+impl From<Direction> for Octant {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Self::North,
+            Direction::East => Self::East,
+            Direction::South => Self::South,
+            Direction::West => Self::West,
+        }
+    }
+}
+
+impl TryFrom<Octant> for Direction {
+    type Error = ConversionError;
+
+    fn try_from(octant: Octant) -> Result<Self, Self::Error> {
+        match octant {
+            Octant::North => Ok(Self::North),
+            Octant::East => Ok(Self::East),
+            Octant::South => Ok(Self::South),
+            Octant::West => Ok(Self::West),
+            _ => Err(ConversionError::DiagonalOctant(octant)),
+        }
+    }
+}
+
+impl Mul<Transform> for Octant {
+    type Output = Self;
+
+    /// Applies a `Transform` to the current `Octant`.
+    ///
+    /// # Panics
+    ///
+    /// This function uses `unwrap()` internally but will never panic because the
+    /// transformation math guarantees that the result will always be a valid `Octant` value (0-7).
+    fn mul(self, rhs: Transform) -> Self::Output {
+        let v: u8 = self.into();
+        Self::try_from(match rhs {
+            Transform::Identity => v,
+            Transform::Rotate90 => (v + 2) % 8,
+            Transform::Rotate180 => (v + 4) % 8,
+            Transform::Rotate270 => (v + 6) % 8,
+            Transform::FlipHorizontal => (8 - v) % 8,
+            Transform::FlipDiagonal => (10 - v) % 8,
+            Transform::FlipVertical => (12 - v) % 8,
+            Transform::FlipAntiDiagonal => (14 - v) % 8,
+        })
+        .unwrap()
+    }
+}
+
+impl MulAssign<Transform> for Octant {
+    fn mul_assign(&mut self, rhs: Transform) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for Octant {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Self::North => Self::South,
+            Self::NorthEast => Self::SouthWest,
+            Self::East => Self::West,
+            Self::SouthEast => Self::NorthWest,
+            Self::South => Self::North,
+            Self::SouthWest => Self::NorthEast,
+            Self::West => Self::East,
+            Self::NorthWest => Self::SouthEast,
+        }
+    }
+}
+
+impl FromStr for Octant {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            ("N", "NORTH", Self::North),
+            ("NE", "NORTHEAST", Self::NorthEast),
+            ("E", "EAST", Self::East),
+            ("SE", "SOUTHEAST", Self::SouthEast),
+            ("S", "SOUTH", Self::South),
+            ("SW", "SOUTHWEST", Self::SouthWest),
+            ("W", "WEST", Self::West),
+            ("NW", "NORTHWEST", Self::NorthWest),
+        ]
+        .into_iter()
+        .find(|(short, long, _)| s.eq_ignore_ascii_case(short) || s.eq_ignore_ascii_case(long))
+        .map(|(_, _, octant)| octant)
+        .ok_or_else(|| ParseError::InvalidOctant(s.into()))
+    }
+}
+
+impl Display for Octant {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        match *self {
+            Self::North => write!(fmt, "N"),
+            Self::NorthEast => write!(fmt, "NE"),
+            Self::East => write!(fmt, "E"),
+            Self::SouthEast => write!(fmt, "SE"),
+            Self::South => write!(fmt, "S"),
+            Self::SouthWest => write!(fmt, "SW"),
+            Self::West => write!(fmt, "W"),
+            Self::NorthWest => write!(fmt, "NW"),
+        }
+    }
+}
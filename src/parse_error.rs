@@ -0,0 +1,200 @@
+//! ## `parse_error`
+//!
+//! The `parse_error` module provides the `ParseError` enum, returned when a string cannot be parsed into one of the
+//! crate's types.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::Deref,
+};
+
+/// Maximum number of bytes of rejected input retained by a `ParseError`.
+pub const MAX_REJECTED_INPUT_LEN: usize = 32;
+
+/// A copy of the input rejected by a parser.
+///
+/// The input is stored inline so that parsing never allocates, and is truncated to at most
+/// `MAX_REJECTED_INPUT_LEN` bytes at a character boundary.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RejectedInput {
+    /// UTF-8 bytes of the retained input, zero-padded.
+    bytes: [u8; MAX_REJECTED_INPUT_LEN],
+    /// Number of bytes of `bytes` in use.
+    len: usize,
+    /// True if the original input was longer than the retained input.
+    truncated: bool,
+}
+
+/// An error produced when parsing a string fails, carrying the rejected input.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum ParseError {
+    /// The input is not a valid `Direction`.
+    InvalidDirection(RejectedInput),
+    /// The character at `position` of a string is not a valid member of a `DirectionSet`.
+    InvalidDirectionSet {
+        /// The rejected character.
+        symbol: RejectedInput,
+        /// Index of the rejected character within the string, counted in characters.
+        position: usize,
+    },
+    /// The input is not a valid `Octant`.
+    InvalidOctant(RejectedInput),
+    /// The input is not a valid `Transform`.
+    InvalidTransform(RejectedInput),
+    /// The character at `position` of a word is not a valid `Transform` symbol.
+    InvalidTransformWord {
+        /// The rejected character.
+        symbol: RejectedInput,
+        /// Index of the rejected character within the word, counted in characters.
+        position: usize,
+    },
+    /// The input is not a valid `Turn`.
+    InvalidTurn(RejectedInput),
+}
+
+impl RejectedInput {
+    /// Copies as much of the input as fits, without splitting a character.
+    #[must_use]
+    pub fn new(input: &str) -> Self {
+        let mut len = input.len().min(MAX_REJECTED_INPUT_LEN);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut bytes = [0; MAX_REJECTED_INPUT_LEN];
+        bytes[..len].copy_from_slice(&input.as_bytes()[..len]);
+        Self {
+            bytes,
+            len,
+            truncated: len < input.len(),
+        }
+    }
+
+    /// Returns the retained input.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// Returns true if the original input was too long to be retained in full.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl ParseError {
+    /// Returns the input string which was rejected.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        self.rejected()
+    }
+
+    /// Returns the position of the rejected character, if the error refers to a single character of a longer string.
+    #[must_use]
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            Self::InvalidDirectionSet { position, .. } | Self::InvalidTransformWord { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+
+    /// Returns the alphabet of strings which would have been accepted.
+    #[must_use]
+    pub const fn expected(&self) -> &'static str {
+        match self {
+            Self::InvalidDirection(_) => "N, E, S, W, North, East, South, West",
+            Self::InvalidDirectionSet { .. } => "N, E, S, W",
+            Self::InvalidOctant(_) => {
+                "N, NE, E, SE, S, SW, W, NW, North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest"
+            }
+            Self::InvalidTransform(_) | Self::InvalidTransformWord { .. } => "I, R, U, L, |, /, -, \\",
+            Self::InvalidTurn(_) => "F, R, B, L, Straight, Right, Back, Left",
+        }
+    }
+
+    /// Returns the retained copy of the rejected input.
+    const fn rejected(&self) -> &RejectedInput {
+        match self {
+            Self::InvalidDirection(input)
+            | Self::InvalidDirectionSet { symbol: input, .. }
+            | Self::InvalidOctant(input)
+            | Self::InvalidTransform(input)
+            | Self::InvalidTurn(input)
+            | Self::InvalidTransformWord { symbol: input, .. } => input,
+        }
+    }
+
+    /// Returns the name of the type which failed to parse.
+    const fn target(&self) -> &'static str {
+        match self {
+            Self::InvalidDirection(_) => "direction",
+            Self::InvalidDirectionSet { .. } => "direction symbol",
+            Self::InvalidOctant(_) => "octant",
+            Self::InvalidTransform(_) => "transform",
+            Self::InvalidTransformWord { .. } => "transform symbol",
+            Self::InvalidTurn(_) => "turn",
+        }
+    }
+}
+
+impl From<&str> for RejectedInput {
+    fn from(input: &str) -> Self {
+        Self::new(input)
+    }
+}
+
+impl Deref for RejectedInput {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for RejectedInput {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for RejectedInput {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Debug for RejectedInput {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(self.as_str(), fmt)
+    }
+}
+
+impl Display for RejectedInput {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        write!(fmt, "{}", self.as_str())?;
+        if self.truncated {
+            write!(fmt, "...")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        let input = self.rejected();
+        write!(
+            fmt,
+            "Invalid {} {:?}{}",
+            self.target(),
+            input.as_str(),
+            if input.is_truncated() { "..." } else { "" }
+        )?;
+        if let Some(position) = self.position() {
+            write!(fmt, " at position {position}")?;
+        }
+        write!(fmt, ", expected one of: {}", self.expected())
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,257 @@
+//! ## `pathfinding`
+//!
+//! The `pathfinding` module provides A* and Dijkstra searches over weighted `Grid`s, returning shortest paths as
+//! sequences of `Direction`s or `Octant`s.
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::{cmp::Ordering, f64::consts::SQRT_2};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ALL_DIRECTIONS, ALL_OCTANTS, Coord, Direction, Grid, Octant};
+
+/// A single step between adjacent cells of a `Grid`, taken by a pathfinding search.
+///
+/// `Direction` moves between cells sharing an edge, and `Octant` also moves diagonally between cells sharing a corner.
+pub trait Move: Copy + Eq + Into<Octant> + 'static {
+    /// Every available move, in the order in which they are tried.
+    const ALL: &'static [Self];
+
+    /// Returns the length of the move: `1` for a cardinal step and `SQRT_2` for a diagonal step.
+    #[must_use]
+    fn length(self) -> f64 {
+        if self.into().is_diagonal() { SQRT_2 } else { 1.0 }
+    }
+}
+
+/// An estimate of the remaining cost between two positions of a `Grid`, used to guide A*.
+///
+/// Each estimate is admissible when every cell costs at least `1` to enter: `Manhattan` for `Direction` moves, and
+/// `Octile` for `Octant` moves. `Chebyshev` is admissible for both, and `Zero` turns A* into Dijkstra's algorithm.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Heuristic {
+    /// Always zero.
+    #[default]
+    Zero,
+    /// The sum of the row and column distances.
+    Manhattan,
+    /// The larger of the row and column distances.
+    Chebyshev,
+    /// The exact distance with unit cardinal and `SQRT_2` diagonal steps and no obstacles.
+    Octile,
+}
+
+/// An entry of the open set, ordered so that `BinaryHeap` pops the lowest estimated total cost first.
+#[derive(Clone, Copy, Debug)]
+#[allow(
+    clippy::redundant_pub_crate,
+    reason = "The entry is shared with `jump_point`, and must not become part of the public API."
+)]
+pub(crate) struct Open {
+    /// Cost so far plus the heuristic estimate of the remaining cost.
+    pub(crate) estimate: f64,
+    /// Cost so far.
+    pub(crate) cost: f64,
+    /// Insertion order, breaking ties in favour of earlier entries.
+    pub(crate) order: usize,
+    /// Index of the search state.
+    pub(crate) state: usize,
+}
+
+impl Move for Direction {
+    const ALL: &'static [Self] = &ALL_DIRECTIONS;
+}
+
+impl Move for Octant {
+    const ALL: &'static [Self] = &ALL_OCTANTS;
+}
+
+impl Heuristic {
+    /// Returns the estimated cost of travelling between two `(row, col)` positions.
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "Grid dimensions are far below the range in which `f64` loses integer precision."
+    )]
+    pub const fn estimate(self, (row_a, col_a): (usize, usize), (row_b, col_b): (usize, usize)) -> f64 {
+        let (rows, cols) = (row_a.abs_diff(row_b) as f64, col_a.abs_diff(col_b) as f64);
+        let (short, long) = if rows < cols { (rows, cols) } else { (cols, rows) };
+        match self {
+            Self::Zero => 0.0,
+            Self::Manhattan => rows + cols,
+            Self::Chebyshev => long,
+            Self::Octile => long + (SQRT_2 - 1.0) * short,
+        }
+    }
+}
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .total_cmp(&self.estimate)
+            .then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the moves and total cost of a cheapest path from `start` to `goal` found by A*, or `None` if the
+    /// `goal` is unreachable or either position lies outside the `Grid`.
+    ///
+    /// `cost` returns the cost of entering a cell, or `None` if the cell is impassable; it is scaled by the
+    /// `Move::length` of each step and must not be negative. The `start` cell is never entered, so its cost is
+    /// ignored. Diagonal moves may not cut the corner of an impassable cell.
+    ///
+    /// The path is optimal whenever the `Heuristic` is admissible for the costs and moves used.
+    pub fn astar<M, C>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        heuristic: Heuristic,
+        cost: C,
+    ) -> Option<(Vec<M>, f64)>
+    where
+        M: Move,
+        C: FnMut(&T) -> Option<f64>,
+    {
+        self.find_path(start, goal, heuristic, cost, None::<fn(M, M) -> f64>)
+    }
+
+    /// Returns the moves and total cost of a cheapest path from `start` to `goal`, as `astar`, where every pair of
+    /// consecutive moves also costs `turn_penalty(previous, next)`.
+    ///
+    /// The penalty is called for straight-on pairs too, so it should return 0 when `previous == next`. Turn penalties
+    /// must not be negative. The first move of a path is never penalised.
+    pub fn astar_with_turns<M, C, P>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        heuristic: Heuristic,
+        cost: C,
+        turn_penalty: P,
+    ) -> Option<(Vec<M>, f64)>
+    where
+        M: Move,
+        C: FnMut(&T) -> Option<f64>,
+        P: FnMut(M, M) -> f64,
+    {
+        self.find_path(start, goal, heuristic, cost, Some(turn_penalty))
+    }
+
+    /// Returns the moves and total cost of a cheapest path from `start` to `goal` found by Dijkstra's algorithm, with
+    /// costs as for `astar`.
+    pub fn dijkstra<M, C>(&self, start: (usize, usize), goal: (usize, usize), cost: C) -> Option<(Vec<M>, f64)>
+    where
+        M: Move,
+        C: FnMut(&T) -> Option<f64>,
+    {
+        self.astar(start, goal, Heuristic::Zero, cost)
+    }
+
+    /// Runs A* over states of a position and, when turns are penalised, the move which reached it.
+    fn find_path<M, C, P>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        heuristic: Heuristic,
+        mut cost: C,
+        mut turn_penalty: Option<P>,
+    ) -> Option<(Vec<M>, f64)>
+    where
+        M: Move,
+        C: FnMut(&T) -> Option<f64>,
+        P: FnMut(M, M) -> f64,
+    {
+        if !self.contains(start) || !self.contains(goal) {
+            return None;
+        }
+        // Each cell has one state per incoming move, plus one for the start, only if the heading matters.
+        let slots = if turn_penalty.is_some() { M::ALL.len() + 1 } else { 1 };
+        let cols = self.cols();
+        let mut best = vec![f64::INFINITY; self.len() * slots];
+        let mut parents: Vec<Option<(usize, M)>> = vec![None; self.len() * slots];
+        let mut open = BinaryHeap::new();
+
+        let start_state = (start.0 * cols + start.1) * slots;
+        best[start_state] = 0.0;
+        open.push(Open {
+            estimate: heuristic.estimate(start, goal),
+            cost: 0.0,
+            order: 0,
+            state: start_state,
+        });
+        let mut order = 1;
+
+        while let Some(Open { cost: so_far, state, .. }) = open.pop() {
+            if so_far > best[state] {
+                continue;
+            }
+            let pos = ((state / slots) / cols, (state / slots) % cols);
+            if pos == goal {
+                let mut moves = Vec::new();
+                let mut current = state;
+                while let Some((previous, step)) = parents[current] {
+                    moves.push(step);
+                    current = previous;
+                }
+                moves.reverse();
+                return Some((moves, so_far));
+            }
+            let heading = (state % slots).checked_sub(1).map(|index| M::ALL[index]);
+
+            for (index, &step) in M::ALL.iter().enumerate() {
+                let Some(next) = self.step(pos, step) else {
+                    continue;
+                };
+                if self.cuts_corner(pos, step.into(), &mut cost) {
+                    continue;
+                }
+                let Some(enter) = cost(&self[next]) else {
+                    continue;
+                };
+                let mut next_cost = so_far + enter * step.length();
+                if let (Some(penalty), Some(previous)) = (turn_penalty.as_mut(), heading) {
+                    next_cost += penalty(previous, step);
+                }
+                let next_state = (next.0 * cols + next.1) * slots + if slots > 1 { index + 1 } else { 0 };
+                if next_cost < best[next_state] {
+                    best[next_state] = next_cost;
+                    parents[next_state] = Some((state, step));
+                    open.push(Open {
+                        estimate: next_cost + heuristic.estimate(next, goal),
+                        cost: next_cost,
+                        order,
+                        state: next_state,
+                    });
+                    order += 1;
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns true if a diagonal move from `pos` would cut the corner of an impassable or missing cell.
+    fn cuts_corner<C: FnMut(&T) -> Option<f64>>(&self, pos: (usize, usize), octant: Octant, cost: &mut C) -> bool {
+        let Coord { x, y } = Coord::from(octant);
+        octant.is_diagonal()
+            && [Coord::new(x, 0), Coord::new(0, y)]
+                .into_iter()
+                .filter_map(Coord::octant)
+                .any(|side| self.step(pos, side).is_none_or(|cell| cost(&self[cell]).is_none()))
+    }
+}
@@ -0,0 +1,211 @@
+//! ## `search`
+//!
+//! The `search` module provides breadth-first searches over `Grid`s: distance fields with `Direction` parent pointers,
+//! connected-component labelling, and flood fill.
+
+use alloc::{collections::VecDeque, vec::Vec};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ALL_DIRECTIONS, ALL_OCTANTS, Direction, Grid, Octant};
+
+/// Which cells count as adjacent when searching a `Grid`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Connectivity {
+    /// Cells sharing an edge, reached by a `Direction`.
+    #[default]
+    Four,
+    /// Cells sharing an edge or a corner, reached by an `Octant`.
+    Eight,
+}
+
+/// The result of a breadth-first search: the distance to each reachable cell, and the `Direction` of the final step
+/// of a shortest path into it.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct DistanceField {
+    /// Number of steps from the nearest source, or `None` if unreachable.
+    distances: Grid<Option<usize>>,
+    /// `Direction` of the step into each cell, or `None` for sources and unreachable cells.
+    parents: Grid<Option<Direction>>,
+}
+
+impl Connectivity {
+    /// Returns the `Octant`s of the adjacent cells, in the order of `ALL_DIRECTIONS` or `ALL_OCTANTS`.
+    #[must_use]
+    pub const fn octants(self) -> &'static [Octant] {
+        match self {
+            Self::Four => &[Octant::North, Octant::East, Octant::South, Octant::West],
+            Self::Eight => &ALL_OCTANTS,
+        }
+    }
+}
+
+impl DistanceField {
+    /// Returns the number of steps from `pos` to the nearest source, or `None` if it is unreachable or outside the
+    /// grid.
+    #[must_use]
+    pub fn distance(&self, pos: (usize, usize)) -> Option<usize> {
+        self.distances.get(pos).copied().flatten()
+    }
+
+    /// Returns the `Direction` of the final step of a shortest path into `pos`, or `None` if `pos` is a source, is
+    /// unreachable, or lies outside the grid.
+    #[must_use]
+    pub fn parent(&self, pos: (usize, usize)) -> Option<Direction> {
+        self.parents.get(pos).copied().flatten()
+    }
+
+    /// Returns the distance of every cell.
+    #[must_use]
+    pub const fn distances(&self) -> &Grid<Option<usize>> {
+        &self.distances
+    }
+
+    /// Returns the parent pointer of every cell.
+    #[must_use]
+    pub const fn parents(&self) -> &Grid<Option<Direction>> {
+        &self.parents
+    }
+
+    /// Returns the `Direction`s of a shortest path from the nearest source to `target`, or `None` if `target` is
+    /// unreachable or outside the grid.
+    ///
+    /// The path to a source is empty.
+    #[must_use]
+    pub fn path_to(&self, target: (usize, usize)) -> Option<Vec<Direction>> {
+        let len = self.distance(target)?;
+        let mut path = Vec::with_capacity(len);
+        let mut pos = target;
+        while let Some(direction) = self.parent(pos) {
+            path.push(direction);
+            pos = self.parents.step(pos, -direction)?;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the distance field of a 4-connected breadth-first search from every source position through the cells
+    /// for which `passable` returns true.
+    ///
+    /// Sources are included even if they are not passable; sources outside the `Grid` are ignored. Ties between
+    /// shortest paths are broken in favour of earlier sources, then of earlier `Direction`s in `ALL_DIRECTIONS`.
+    pub fn bfs<I, F>(&self, sources: I, mut passable: F) -> DistanceField
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+        F: FnMut(&T) -> bool,
+    {
+        let mut distances = Grid::new(self.dim(), None);
+        let mut parents = Grid::new(self.dim(), None);
+        let mut queue = VecDeque::new();
+        for source in sources {
+            if let Some(distance @ None) = distances.get_mut(source) {
+                *distance = Some(0);
+                queue.push_back(source);
+            }
+        }
+        while let Some(pos) = queue.pop_front() {
+            let next_distance = distances[pos].map(|distance: usize| distance + 1);
+            for direction in ALL_DIRECTIONS {
+                let Some(next) = self.step(pos, direction) else {
+                    continue;
+                };
+                if distances[next].is_none() && passable(&self[next]) {
+                    distances[next] = next_distance;
+                    parents[next] = Some(direction);
+                    queue.push_back(next);
+                }
+            }
+        }
+        DistanceField { distances, parents }
+    }
+
+    /// Returns the positions of the cells connected to `start` through cells for which `predicate` returns true, in
+    /// breadth-first order.
+    ///
+    /// The result is empty if `start` lies outside the `Grid` or does not satisfy `predicate`.
+    pub fn flood<F: FnMut(&T) -> bool>(
+        &self,
+        start: (usize, usize),
+        connectivity: Connectivity,
+        mut predicate: F,
+    ) -> Vec<(usize, usize)> {
+        let mut region = Vec::new();
+        if !self.get(start).is_some_and(&mut predicate) {
+            return region;
+        }
+        let mut visited = Grid::new(self.dim(), false);
+        visited[start] = true;
+        region.push(start);
+        let mut index = 0;
+        while let Some(&pos) = region.get(index) {
+            index += 1;
+            for &octant in connectivity.octants() {
+                let Some(next) = self.step(pos, octant) else {
+                    continue;
+                };
+                if !visited[next] && predicate(&self[next]) {
+                    visited[next] = true;
+                    region.push(next);
+                }
+            }
+        }
+        region
+    }
+
+    /// Sets every cell returned by `flood` to `value`, returning the number of cells filled.
+    pub fn flood_fill<F: FnMut(&T) -> bool>(
+        &mut self,
+        start: (usize, usize),
+        connectivity: Connectivity,
+        predicate: F,
+        value: T,
+    ) -> usize
+    where
+        T: Clone,
+    {
+        let region = self.flood(start, connectivity, predicate);
+        for &pos in &region {
+            self[pos] = value.clone();
+        }
+        region.len()
+    }
+
+    /// Labels the connected components of the cells for which `member` returns true, returning the label of each cell
+    /// and the number of components.
+    ///
+    /// Labels count up from zero in the row-major order of each component's first cell. Cells which are not members
+    /// are labelled `None`.
+    pub fn label_components<F: FnMut(&T) -> bool>(
+        &self,
+        connectivity: Connectivity,
+        mut member: F,
+    ) -> (Grid<Option<usize>>, usize) {
+        let members = self.map(&mut member);
+        let mut labels = Grid::new(self.dim(), None);
+        let mut count = 0;
+        let mut queue = VecDeque::new();
+        for (start, &is_member) in members.indexed_iter() {
+            if !is_member || labels[start].is_some() {
+                continue;
+            }
+            labels[start] = Some(count);
+            queue.push_back(start);
+            while let Some(pos) = queue.pop_front() {
+                for &octant in connectivity.octants() {
+                    let Some(next) = self.step(pos, octant) else {
+                        continue;
+                    };
+                    if members[next] && labels[next].is_none() {
+                        labels[next] = Some(count);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            count += 1;
+        }
+        (labels, count)
+    }
+}
@@ -0,0 +1,122 @@
+//! ## `symmetry`
+//!
+//! The `symmetry` module provides methods for analysing the symmetries of 2D arrays under the `Transform`s of D4.
+
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    iter::FusedIterator,
+};
+use ndarray::{Array2, ArrayBase, ArrayView2, Data, Ix2};
+
+use crate::{ALL_TRANSFORMS, Transform, TransformSet, TransformSetIter};
+
+/// An iterator over the distinct orientations of an array, created by `Transform::orbit`.
+pub struct Orbit<'a, S: Data> {
+    /// The array being transformed.
+    arr: &'a ArrayBase<S, Ix2>,
+    /// One `Transform` for each distinct orientation which has not yet been yielded.
+    transforms: TransformSetIter,
+}
+
+impl Transform {
+    /// Returns the stabilizer of the array: the subgroup of `Transform`s which leave it unchanged.
+    ///
+    /// Each `Transform` is checked against a zero-copy view of the array, stopping at the first differing element,
+    /// so no transformed copies are allocated.
+    #[must_use]
+    pub fn stabilizer<S, A>(arr: &ArrayBase<S, Ix2>) -> TransformSet
+    where
+        S: Data<Elem = A>,
+        A: PartialEq,
+    {
+        ALL_TRANSFORMS
+            .into_iter()
+            .filter(|transform| transform.is_identity() || transform.view(arr) == arr)
+            .collect()
+    }
+
+    /// Returns the canonical orientation of the array under D4, together with the `Transform` which produces it.
+    ///
+    /// The canonical orientation is the smallest of the eight orientations, comparing first their `(rows, cols)`
+    /// dimensions and then their elements in row-major order. Ties are broken in favour of the earliest `Transform` in
+    /// `ALL_TRANSFORMS`, so the returned `Transform` always satisfies `transform * arr == canonical`.
+    ///
+    /// Orientations are compared through zero-copy views which stop at the first differing element; only the
+    /// canonical orientation is copied into the returned array.
+    #[must_use]
+    pub fn canonicalize<S, A>(arr: &ArrayBase<S, Ix2>) -> (Array2<A>, Self)
+    where
+        S: Data<Elem = A>,
+        A: Ord + Clone,
+    {
+        let mut best = (arr.view(), Self::Identity);
+        for transform in ALL_TRANSFORMS.into_iter().skip(1) {
+            let view = transform.view(arr);
+            if Self::cmp_orientations(&view, &best.0) == Ordering::Less {
+                best = (view, transform);
+            }
+        }
+        (best.0.to_owned(), best.1)
+    }
+
+    /// Returns an iterator over each distinct orientation of the array, together with the `Transform` producing it.
+    ///
+    /// Two `Transform`s produce the same orientation exactly when they lie in the same right coset of the array's
+    /// stabilizer, so one representative is yielded per coset: the first in the order of `ALL_TRANSFORMS`.
+    #[must_use]
+    pub fn orbit<S, A>(arr: &ArrayBase<S, Ix2>) -> Orbit<'_, S>
+    where
+        S: Data<Elem = A>,
+        A: PartialEq + Clone,
+    {
+        Orbit {
+            arr,
+            transforms: Self::stabilizer(arr).right_transversal().iter(),
+        }
+    }
+
+    /// Compares two orientations of an array by their dimensions, then by their elements in row-major order.
+    fn cmp_orientations<A: Ord>(a: &ArrayView2<'_, A>, b: &ArrayView2<'_, A>) -> Ordering {
+        a.dim().cmp(&b.dim()).then_with(|| a.iter().cmp(b.iter()))
+    }
+}
+
+impl<S, A> Iterator for Orbit<'_, S>
+where
+    S: Data<Elem = A>,
+    A: Clone,
+{
+    type Item = (Transform, Array2<A>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.transforms.next().map(|transform| (transform, transform * self.arr))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.transforms.size_hint()
+    }
+}
+
+impl<S, A> ExactSizeIterator for Orbit<'_, S>
+where
+    S: Data<Elem = A>,
+    A: Clone,
+{
+}
+
+impl<S, A> FusedIterator for Orbit<'_, S>
+where
+    S: Data<Elem = A>,
+    A: Clone,
+{
+}
+
+impl<S: Data> Debug for Orbit<'_, S> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        fmt.debug_struct("Orbit")
+            .field("dim", &self.arr.dim())
+            .field("transforms", &self.transforms)
+            .finish()
+    }
+}
@@ -2,16 +2,23 @@
 //!
 //! The `transform` module provides the `Transform` enum, which represents the dihedral group D4.
 
-#[cfg(feature = "array")]
-use ndarray::{Array, ArrayBase, Data, Ix2, s};
-use num_enum::{IntoPrimitive, TryFromPrimitive};
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-use std::{
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{Mul, MulAssign, Neg},
     str::FromStr,
 };
+#[cfg(feature = "array")]
+use ndarray::{
+    Array, ArrayBase, ArrayView, ArrayView2, ArrayViewMut, ArrayViewMut2, Axis, Data, DataMut, Dimension, ErrorKind, Ix2,
+    RawData, ShapeError,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Convention, ParseError};
 
 /// All transformations in their order of definition.
 pub const ALL_TRANSFORMS: [Transform; 8] = [
@@ -69,14 +76,64 @@ impl Transform {
             Self::FlipHorizontal | Self::FlipDiagonal | Self::FlipVertical | Self::FlipAntiDiagonal
         )
     }
-}
 
-impl Mul for Transform {
-    type Output = Self;
+    /// Returns the single character symbol of the `Transform`.
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Identity => "I",
+            Self::Rotate90 => "R",
+            Self::Rotate180 => "U",
+            Self::Rotate270 => "L",
+            Self::FlipHorizontal => "|",
+            Self::FlipDiagonal => "/",
+            Self::FlipVertical => "-",
+            Self::FlipAntiDiagonal => "\\",
+        }
+    }
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        let a: u8 = self.into();
-        let b: u8 = rhs.into();
+    /// Returns the `Transform` with the given symbol, ignoring ASCII case, or `None` if there is no such `Transform`.
+    #[must_use]
+    pub const fn from_symbol(symbol: char) -> Option<Self> {
+        match symbol.to_ascii_uppercase() {
+            'I' => Some(Self::Identity),
+            'R' => Some(Self::Rotate90),
+            'U' => Some(Self::Rotate180),
+            'L' => Some(Self::Rotate270),
+            '|' => Some(Self::FlipHorizontal),
+            '/' => Some(Self::FlipDiagonal),
+            '-' => Some(Self::FlipVertical),
+            '\\' => Some(Self::FlipAntiDiagonal),
+            _ => None,
+        }
+    }
+
+    /// Parses a word of `Transform` symbols, such as `"RR|/"`, and returns its product.
+    ///
+    /// The symbols are multiplied together from left to right, so `"RR|/"` is equivalent to
+    /// `Rotate90 * Rotate90 * FlipHorizontal * FlipDiagonal`. The empty word is the `Identity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidTransformWord` error identifying the first character which is not a `Transform` symbol.
+    pub fn parse_word(word: &str) -> Result<Self, ParseError> {
+        word.char_indices()
+            .enumerate()
+            .try_fold(Self::Identity, |product, (position, (offset, symbol))| {
+                Self::from_symbol(symbol)
+                    .map(|transform| product * transform)
+                    .ok_or_else(|| ParseError::InvalidTransformWord {
+                        symbol: word[offset..offset + symbol.len_utf8()].into(),
+                        position,
+                    })
+            })
+    }
+
+    /// Returns the composition `self * rhs`, usable in `const` contexts.
+    #[must_use]
+    pub const fn compose(self, rhs: Self) -> Self {
+        let a = self as u8;
+        let b = rhs as u8;
 
         // Determine if each is a flip (IDs 4–7) and extract the rotation exponent
         let (is_flip_a, k_a) = (a >= 4, a % 4);
@@ -89,7 +146,397 @@ impl Mul for Transform {
             (true, true) => (k_a + 4 - k_b) % 4,        // (r^i f) * (r^j f) = r^(i-j)
         };
 
-        Self::try_from(id).unwrap()
+        ALL_TRANSFORMS[id as usize]
+    }
+
+    /// Returns the inverse `-self`, usable in `const` contexts.
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Rotate90 => Self::Rotate270,
+            Self::Rotate270 => Self::Rotate90,
+            // Every other element is its own inverse.
+            _ => self,
+        }
+    }
+
+    /// Returns the order of the `Transform`: the smallest positive `n` for which `self.pow(n)` is the `Identity`.
+    #[must_use]
+    pub const fn order(self) -> u8 {
+        match self {
+            Self::Identity => 1,
+            Self::Rotate90 | Self::Rotate270 => 4,
+            _ => 2,
+        }
+    }
+
+    /// Returns the `Transform` composed with itself `n` times.
+    ///
+    /// Negative powers are powers of the inverse, and `self.pow(0)` is the `Identity`.
+    #[must_use]
+    pub const fn pow(self, n: i32) -> Self {
+        let mut result = Self::Identity;
+        let mut i = n.rem_euclid(self.order() as i32);
+        while i > 0 {
+            result = result.compose(self);
+            i -= 1;
+        }
+        result
+    }
+
+    /// Returns the conjugate of `other` by `self`: `self * other * -self`.
+    #[must_use]
+    pub const fn conjugate(self, other: Self) -> Self {
+        self.compose(other).compose(self.inverse())
+    }
+
+    /// Returns the commutator of `self` and `other`: `self * other * -self * -other`.
+    ///
+    /// The commutator is the `Identity` if and only if the two `Transform`s commute.
+    #[must_use]
+    pub const fn commutator(self, other: Self) -> Self {
+        self.compose(other).compose(self.inverse()).compose(other.inverse())
+    }
+
+    /// Returns the determinant of the `Transform`'s matrix: `+1` for the rotations and `Identity`, `-1` for the flips.
+    #[must_use]
+    pub const fn determinant(self) -> i8 {
+        if self.is_flip() { -1 } else { 1 }
+    }
+
+    /// Returns the integer matrix of the `Transform`, acting on column vectors `(x, y)` with the y-axis pointing
+    /// North, as listed on each variant.
+    ///
+    /// The matrix of `a * b` is the matrix product of the matrices of `a` and `b`, in that order.
+    #[must_use]
+    pub const fn to_matrix(self) -> [[i8; 2]; 2] {
+        self.to_matrix_in(Convention::MATH)
+    }
+
+    /// Returns the integer matrix of the `Transform`, acting on column vectors `(x, y)` with the y-axis of the
+    /// `Convention`.
+    ///
+    /// The `Transform` keeps its compass meaning in every `Convention`, so `Rotate90` maps `(x, y) -> (-y, x)` when
+    /// the y-axis points South.
+    #[must_use]
+    pub const fn to_matrix_in(self, convention: Convention) -> [[i8; 2]; 2] {
+        let [[a, b], [c, d]] = match self {
+            Self::Identity => [[1, 0], [0, 1]],
+            Self::Rotate90 => [[0, 1], [-1, 0]],
+            Self::Rotate180 => [[-1, 0], [0, -1]],
+            Self::Rotate270 => [[0, -1], [1, 0]],
+            Self::FlipHorizontal => [[-1, 0], [0, 1]],
+            Self::FlipDiagonal => [[0, 1], [1, 0]],
+            Self::FlipVertical => [[1, 0], [0, -1]],
+            Self::FlipAntiDiagonal => [[0, -1], [-1, 0]],
+        };
+        // Reversing the y-axis conjugates the matrix by `diag(1, -1)`, negating the off-diagonal entries.
+        if convention.is_y_down() {
+            [[a, -b], [-c, d]]
+        } else {
+            [[a, b], [c, d]]
+        }
+    }
+
+    /// Returns the `Transform` with the given matrix, or `None` if the matrix does not represent a member of D4.
+    #[must_use]
+    pub const fn from_matrix(matrix: [[i8; 2]; 2]) -> Option<Self> {
+        Self::from_matrix_in(matrix, Convention::MATH)
+    }
+
+    /// Returns the `Transform` with the given matrix in the `Convention`, or `None` if the matrix does not represent a
+    /// member of D4.
+    #[must_use]
+    pub const fn from_matrix_in(matrix: [[i8; 2]; 2], convention: Convention) -> Option<Self> {
+        let mut i = 0;
+        while i < ALL_TRANSFORMS.len() {
+            let [[a, b], [c, d]] = ALL_TRANSFORMS[i].to_matrix_in(convention);
+            if a == matrix[0][0] && b == matrix[0][1] && c == matrix[1][0] && d == matrix[1][1] {
+                return Some(ALL_TRANSFORMS[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns true if the `Transform` exchanges the width and height of a grid.
+    #[must_use]
+    pub const fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Self::Rotate90 | Self::Rotate270 | Self::FlipDiagonal | Self::FlipAntiDiagonal
+        )
+    }
+
+    /// Returns the `(rows, cols)` dimensions of a grid after the `Transform` has been applied to it.
+    #[must_use]
+    pub const fn apply_dims(self, (rows, cols): (usize, usize)) -> (usize, usize) {
+        if self.swaps_axes() { (cols, rows) } else { (rows, cols) }
+    }
+
+    /// Returns the `(row, col)` index within the transformed grid of the cell found at `(row, col)` within a grid
+    /// of dimensions `(rows, cols)`.
+    ///
+    /// The result is consistent with the index space produced by applying the `Transform` to an array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index lies outside of the given dimensions.
+    #[must_use]
+    pub const fn apply_index(self, (rows, cols): (usize, usize), (row, col): (usize, usize)) -> (usize, usize) {
+        assert!(row < rows && col < cols, "Index out of bounds");
+        let (last_row, last_col) = (rows - 1, cols - 1);
+        match self {
+            Self::Identity => (row, col),
+            Self::Rotate90 => (col, last_row - row),
+            Self::Rotate180 => (last_row - row, last_col - col),
+            Self::Rotate270 => (last_col - col, row),
+            Self::FlipHorizontal => (row, last_col - col),
+            Self::FlipDiagonal => (col, row),
+            Self::FlipVertical => (last_row - row, col),
+            Self::FlipAntiDiagonal => (last_col - col, last_row - row),
+        }
+    }
+
+    /// Returns the `Transform` which maps the compass heading of a step between two cells to its heading once both
+    /// have been moved by `apply_index`.
+    ///
+    /// Rows grow downwards, so transposing indices as `FlipDiagonal` does sends North to West, which is the compass
+    /// action of `FlipAntiDiagonal`. The two diagonal flips therefore exchange, and every other `Transform` maps
+    /// headings as itself.
+    #[must_use]
+    pub const fn index_heading(self) -> Self {
+        match self {
+            Self::FlipDiagonal => Self::FlipAntiDiagonal,
+            Self::FlipAntiDiagonal => Self::FlipDiagonal,
+            other => other,
+        }
+    }
+
+    /// Writes a row-major buffer of `width * height` elements into `dst` with the `Transform` applied, returning the
+    /// `(width, height)` of the transformed buffer.
+    ///
+    /// The result matches applying the `Transform` to an array of `height` rows and `width` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` does not hold exactly `width * height` elements, or if `dst` is not the same length as `src`.
+    pub fn apply_buffer_into<T: Clone>(self, src: &[T], width: usize, height: usize, dst: &mut [T]) -> (usize, usize) {
+        assert!(src.len() == width * height, "Buffer length does not match dimensions");
+        assert!(dst.len() == src.len(), "Destination length does not match source");
+        let (rows, cols) = self.apply_dims((height, width));
+        let inverse = self.inverse();
+        for (index, cell) in dst.iter_mut().enumerate() {
+            let (row, col) = inverse.apply_index((rows, cols), (index / cols, index % cols));
+            cell.clone_from(&src[row * width + col]);
+        }
+        (cols, rows)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Transform {
+    /// Returns a copy of a row-major buffer of `width * height` elements with the `Transform` applied, together with
+    /// the `(width, height)` of the transformed buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` does not hold exactly `width * height` elements.
+    #[must_use]
+    pub fn apply_buffer<T: Clone>(self, src: &[T], width: usize, height: usize) -> (Vec<T>, usize, usize) {
+        let mut dst = src.to_vec();
+        let (width, height) = self.apply_buffer_into(src, width, height, &mut dst);
+        (dst, width, height)
+    }
+
+    /// Returns a copy of a grid stored as a list of equal-length rows with the `Transform` applied.
+    ///
+    /// An empty list of rows is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows are not all the same length.
+    #[must_use]
+    pub fn apply_nested<T: Clone, R: AsRef<[T]>>(self, src: &[R]) -> Vec<Vec<T>> {
+        let width = src.first().map_or(0, |row| row.as_ref().len());
+        assert!(
+            src.iter().all(|row| row.as_ref().len() == width),
+            "Rows must all have the same length"
+        );
+        let (rows, cols) = self.apply_dims((src.len(), width));
+        let inverse = self.inverse();
+        (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| {
+                        let (src_row, src_col) = inverse.apply_index((rows, cols), (row, col));
+                        src[src_row].as_ref()[src_col].clone()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "array")]
+impl Transform {
+    /// Returns a zero-copy view of the array with the `Transform` applied.
+    ///
+    /// The view has the same contents as `self * arr`, but is produced by reordering the array's strides
+    /// rather than copying its elements.
+    #[must_use]
+    pub fn view<S, A>(self, arr: &ArrayBase<S, Ix2>) -> ArrayView2<'_, A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.orient(arr.view(), Axis(0), Axis(1))
+    }
+
+    /// Returns a zero-copy mutable view of the array with the `Transform` applied.
+    ///
+    /// Writing to element `(row, col)` of the view writes to the element of `arr` found at
+    /// `(-self).apply_index(self.apply_dims(arr.dim()), (row, col))`.
+    #[must_use]
+    pub fn view_mut<S, A>(self, arr: &mut ArrayBase<S, Ix2>) -> ArrayViewMut2<'_, A>
+    where
+        S: DataMut<Elem = A>,
+    {
+        self.orient(arr.view_mut(), Axis(0), Axis(1))
+    }
+
+    /// Applies the `Transform` to a square array in place, without allocating.
+    ///
+    /// Elements are moved by following the cycles of the permutation described by `apply_index`,
+    /// so the result is identical to `self * &*arr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IncompatibleShape` error if the array is not square.
+    pub fn apply_in_place<S, A>(self, arr: &mut ArrayBase<S, Ix2>) -> Result<(), ShapeError>
+    where
+        S: DataMut<Elem = A>,
+    {
+        let dims = arr.dim();
+        if dims.0 != dims.1 {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+        }
+        if self.is_identity() {
+            return Ok(());
+        }
+
+        for row in 0..dims.0 {
+            for col in 0..dims.1 {
+                // Every cycle of a D4 permutation has length 1, 2 or 4.
+                let start = (row, col);
+                let mut cycle = [start; 4];
+                let mut len = 1;
+                let mut next = self.apply_index(dims, start);
+                while next != start {
+                    cycle[len] = next;
+                    len += 1;
+                    next = self.apply_index(dims, next);
+                }
+
+                // Only rotate each cycle once, from its smallest index.
+                if cycle[1..len].iter().all(|&index| index > start) {
+                    for &index in &cycle[1..len] {
+                        arr.swap(start, index);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of an array of any dimensionality with the `Transform` applied to the plane spanned by two of its
+    /// axes.
+    ///
+    /// `row` and `col` play the roles of axes `0` and `1` of a 2D array, so for an `Array3` indexed `(z, y, x)`,
+    /// `transform.apply_axes(&arr, Axis(1), Axis(2))` applies the `Transform` to every z-slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` and `col` are the same axis, or if either is out of bounds.
+    #[must_use]
+    pub fn apply_axes<S, A, D>(self, arr: &ArrayBase<S, D>, row: Axis, col: Axis) -> Array<A, D>
+    where
+        S: Data<Elem = A>,
+        A: Clone,
+        D: Dimension,
+    {
+        self.view_axes(arr, row, col).to_owned()
+    }
+
+    /// Returns a zero-copy view of an array of any dimensionality with the `Transform` applied to the plane spanned by
+    /// two of its axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` and `col` are the same axis, or if either is out of bounds.
+    #[must_use]
+    pub fn view_axes<S, A, D>(self, arr: &ArrayBase<S, D>, row: Axis, col: Axis) -> ArrayView<'_, A, D>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        self.orient(arr.view(), row, col)
+    }
+
+    /// Returns a zero-copy mutable view of an array of any dimensionality with the `Transform` applied to the plane
+    /// spanned by two of its axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` and `col` are the same axis, or if either is out of bounds.
+    #[must_use]
+    pub fn view_axes_mut<S, A, D>(self, arr: &mut ArrayBase<S, D>, row: Axis, col: Axis) -> ArrayViewMut<'_, A, D>
+    where
+        S: DataMut<Elem = A>,
+        D: Dimension,
+    {
+        self.orient(arr.view_mut(), row, col)
+    }
+
+    /// Reorders the axes and strides of an array so that the plane spanned by `row` and `col` is indexed as the
+    /// transformed plane.
+    fn orient<S: RawData, D: Dimension>(self, mut arr: ArrayBase<S, D>, row: Axis, col: Axis) -> ArrayBase<S, D> {
+        assert!(row != col, "Transform axes must be distinct");
+        assert!(
+            row.index() < arr.ndim() && col.index() < arr.ndim(),
+            "Transform axes out of bounds"
+        );
+        match self {
+            Self::Identity => {}
+            Self::Rotate90 => {
+                arr.swap_axes(row.index(), col.index());
+                arr.invert_axis(col);
+            }
+            Self::Rotate180 => {
+                arr.invert_axis(row);
+                arr.invert_axis(col);
+            }
+            Self::Rotate270 => {
+                arr.swap_axes(row.index(), col.index());
+                arr.invert_axis(row);
+            }
+            Self::FlipHorizontal => arr.invert_axis(col),
+            Self::FlipDiagonal => arr.swap_axes(row.index(), col.index()),
+            Self::FlipVertical => arr.invert_axis(row),
+            Self::FlipAntiDiagonal => {
+                arr.invert_axis(row);
+                arr.invert_axis(col);
+                arr.swap_axes(row.index(), col.index());
+            }
+        }
+        arr
+    }
+}
+
+impl Mul for Transform {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(rhs)
     }
 }
 
@@ -108,17 +555,7 @@ where
     type Output = Array<A, Ix2>;
 
     fn mul(self, rhs: ArrayBase<S, Ix2>) -> Self::Output {
-        let v = rhs.view();
-        match self {
-            Self::Identity => v.to_owned(),
-            Self::Rotate90 => v.t().slice(s![.., ..;-1]).to_owned(),
-            Self::Rotate180 => v.slice(s![..;-1, ..;-1]).to_owned(),
-            Self::Rotate270 => v.t().slice(s![..;-1, ..]).to_owned(),
-            Self::FlipHorizontal => v.slice(s![.., ..;-1]).to_owned(),
-            Self::FlipVertical => v.slice(s![..;-1, ..]).to_owned(),
-            Self::FlipDiagonal => v.t().to_owned(),
-            Self::FlipAntiDiagonal => v.slice(s![..;-1, ..;-1]).t().to_owned(),
-        }
+        self.view(&rhs).to_owned()
     }
 }
 
@@ -131,7 +568,7 @@ where
     type Output = Array<A, Ix2>;
 
     fn mul(self, rhs: &'a ArrayBase<S, Ix2>) -> Self::Output {
-        self * rhs.view()
+        self.view(rhs).to_owned()
     }
 }
 
@@ -139,48 +576,23 @@ impl Neg for Transform {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        match self {
-            Self::Identity => Self::Identity,
-            Self::Rotate90 => Self::Rotate270,
-            Self::Rotate180 => Self::Rotate180,
-            Self::Rotate270 => Self::Rotate90,
-            Self::FlipHorizontal => Self::FlipHorizontal,
-            Self::FlipDiagonal => Self::FlipDiagonal,
-            Self::FlipVertical => Self::FlipVertical,
-            Self::FlipAntiDiagonal => Self::FlipAntiDiagonal,
-        }
+        self.inverse()
     }
 }
 
 impl FromStr for Transform {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_uppercase().as_str() {
-            "I" => Ok(Self::Identity),
-            "R" => Ok(Self::Rotate90),
-            "U" => Ok(Self::Rotate180),
-            "L" => Ok(Self::Rotate270),
-            "|" => Ok(Self::FlipHorizontal),
-            "/" => Ok(Self::FlipDiagonal),
-            "-" => Ok(Self::FlipVertical),
-            "\\" => Ok(Self::FlipAntiDiagonal),
-            _ => Err("Invalid transform, expected one of: I, R, U, L, |, /, -, \\"),
-        }
+        ALL_TRANSFORMS
+            .into_iter()
+            .find(|transform| s.eq_ignore_ascii_case(transform.symbol()))
+            .ok_or_else(|| ParseError::InvalidTransform(s.into()))
     }
 }
 
 impl Display for Transform {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
-        match *self {
-            Self::Identity => write!(fmt, "I"),
-            Self::Rotate90 => write!(fmt, "R"),
-            Self::Rotate180 => write!(fmt, "U"),
-            Self::Rotate270 => write!(fmt, "L"),
-            Self::FlipHorizontal => write!(fmt, "|"),
-            Self::FlipDiagonal => write!(fmt, "/"),
-            Self::FlipVertical => write!(fmt, "-"),
-            Self::FlipAntiDiagonal => write!(fmt, "\\"),
-        }
+        write!(fmt, "{}", self.symbol())
     }
 }
@@ -0,0 +1,436 @@
+//! ## `transform_set`
+//!
+//! The `transform_set` module provides the `TransformSet` struct, a set of `Transform`s used to describe subgroups and
+//! cosets of the dihedral group D4.
+
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::FusedIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, Not, Sub, SubAssign},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ALL_TRANSFORMS, Transform};
+
+/// All ten subgroups of D4, ordered by size.
+pub const ALL_SUBGROUPS: [TransformSet; 10] = [
+    TransformSet::TRIVIAL,
+    TransformSet::from_transforms(&[Transform::Identity, Transform::Rotate180]),
+    TransformSet::from_transforms(&[Transform::Identity, Transform::FlipHorizontal]),
+    TransformSet::from_transforms(&[Transform::Identity, Transform::FlipDiagonal]),
+    TransformSet::from_transforms(&[Transform::Identity, Transform::FlipVertical]),
+    TransformSet::from_transforms(&[Transform::Identity, Transform::FlipAntiDiagonal]),
+    TransformSet::ROTATIONS,
+    TransformSet::from_transforms(&[
+        Transform::Identity,
+        Transform::Rotate180,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+    ]),
+    TransformSet::from_transforms(&[
+        Transform::Identity,
+        Transform::Rotate180,
+        Transform::FlipDiagonal,
+        Transform::FlipAntiDiagonal,
+    ]),
+    TransformSet::ALL,
+];
+
+/// A set of `Transform`s, stored as a bitset over the `Transform` discriminants.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TransformSet {
+    /// Bit `i` is set if the `Transform` with discriminant `i` is a member.
+    bits: u8,
+}
+
+/// An iterator over the members of a `TransformSet`, in the order of `ALL_TRANSFORMS`.
+#[derive(Clone, Debug)]
+pub struct TransformSetIter {
+    /// Members which have not yet been yielded.
+    remaining: TransformSet,
+}
+
+impl TransformSet {
+    /// The set containing no `Transform`s.
+    pub const EMPTY: Self = Self::from_bits(0);
+    /// The trivial subgroup, containing only the `Identity`.
+    pub const TRIVIAL: Self = Self::from_bits(0b0000_0001);
+    /// The cyclic subgroup C4 of rotations.
+    pub const ROTATIONS: Self = Self::from_bits(0b0000_1111);
+    /// The whole group D4.
+    pub const ALL: Self = Self::from_bits(0b1111_1111);
+
+    /// Constructs a `TransformSet` from its bit representation, where bit `i` represents the `Transform` with
+    /// discriminant `i`.
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self { bits }
+    }
+
+    /// Returns the bit representation of the `TransformSet`.
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.bits
+    }
+
+    /// Constructs a `TransformSet` containing the given `Transform`s.
+    #[must_use]
+    pub const fn from_transforms(transforms: &[Transform]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < transforms.len() {
+            set = set.with(transforms[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// Returns the number of `Transform`s in the set.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns true if the set contains no `Transform`s.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns true if the set contains the given `Transform`.
+    #[must_use]
+    pub const fn contains(self, transform: Transform) -> bool {
+        self.bits & Self::bit(transform) != 0
+    }
+
+    /// Returns a copy of the set with the given `Transform` added.
+    #[must_use]
+    pub const fn with(self, transform: Transform) -> Self {
+        Self::from_bits(self.bits | Self::bit(transform))
+    }
+
+    /// Returns a copy of the set with the given `Transform` removed.
+    #[must_use]
+    pub const fn without(self, transform: Transform) -> Self {
+        Self::from_bits(self.bits & !Self::bit(transform))
+    }
+
+    /// Adds a `Transform` to the set, returning true if it was not already present.
+    pub const fn insert(&mut self, transform: Transform) -> bool {
+        let inserted = !self.contains(transform);
+        *self = self.with(transform);
+        inserted
+    }
+
+    /// Removes a `Transform` from the set, returning true if it was present.
+    pub const fn remove(&mut self, transform: Transform) -> bool {
+        let removed = self.contains(transform);
+        *self = self.without(transform);
+        removed
+    }
+
+    /// Returns an iterator over the members of the set, in the order of `ALL_TRANSFORMS`.
+    #[must_use]
+    pub const fn iter(self) -> TransformSetIter {
+        TransformSetIter { remaining: self }
+    }
+
+    /// Returns the set of `Transform`s in either set.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self::from_bits(self.bits | other.bits)
+    }
+
+    /// Returns the set of `Transform`s in both sets.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::from_bits(self.bits & other.bits)
+    }
+
+    /// Returns the set of `Transform`s in `self` but not in `other`.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self::from_bits(self.bits & !other.bits)
+    }
+
+    /// Returns true if every member of `self` is also a member of `other`.
+    #[must_use]
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.bits & !other.bits == 0
+    }
+
+    /// Returns true if the set is a subgroup of D4: it contains the `Identity` and is closed under composition.
+    #[must_use]
+    pub const fn is_subgroup(self) -> bool {
+        self.contains(Transform::Identity) && self.products(self).is_subset(self)
+    }
+
+    /// Returns the smallest subgroup containing all of the given `Transform`s.
+    #[must_use]
+    pub const fn generated_by(generators: &[Transform]) -> Self {
+        let mut group = Self::TRIVIAL.union(Self::from_transforms(generators));
+        loop {
+            let next = group.products(group);
+            if next.bits == group.bits {
+                return group;
+            }
+            group = next;
+        }
+    }
+
+    /// Returns the left coset `transform * self`.
+    #[must_use]
+    pub const fn left_coset(self, transform: Transform) -> Self {
+        Self::EMPTY.with(transform).products(self)
+    }
+
+    /// Returns the right coset `self * transform`.
+    #[must_use]
+    pub const fn right_coset(self, transform: Transform) -> Self {
+        self.products(Self::EMPTY.with(transform))
+    }
+
+    /// Returns the set `transform * self * -transform` of conjugates of each member by `transform`.
+    #[must_use]
+    pub const fn conjugate(self, transform: Transform) -> Self {
+        let mut result = Self::EMPTY;
+        let mut i = 0;
+        while i < ALL_TRANSFORMS.len() {
+            if self.contains(ALL_TRANSFORMS[i]) {
+                result = result.with(transform.conjugate(ALL_TRANSFORMS[i]));
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns the normalizer of the set: every `Transform` whose conjugate of the set is the set itself.
+    #[must_use]
+    pub const fn normalizer(self) -> Self {
+        let mut result = Self::EMPTY;
+        let mut i = 0;
+        while i < ALL_TRANSFORMS.len() {
+            if self.conjugate(ALL_TRANSFORMS[i]).bits == self.bits {
+                result = result.with(ALL_TRANSFORMS[i]);
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns true if the set is a normal subgroup of D4.
+    #[must_use]
+    pub const fn is_normal_subgroup(self) -> bool {
+        self.is_subgroup() && self.normalizer().bits == Self::ALL.bits
+    }
+
+    /// Returns one representative from each left coset `t * self`, choosing the first member of each coset in the
+    /// order of `ALL_TRANSFORMS`.
+    #[must_use]
+    pub const fn left_transversal(self) -> Self {
+        let mut result = Self::EMPTY;
+        let mut covered = Self::EMPTY;
+        let mut i = 0;
+        while i < ALL_TRANSFORMS.len() {
+            if !covered.contains(ALL_TRANSFORMS[i]) {
+                result = result.with(ALL_TRANSFORMS[i]);
+                covered = covered.union(self.left_coset(ALL_TRANSFORMS[i]));
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns one representative from each right coset `self * t`, choosing the first member of each coset in the
+    /// order of `ALL_TRANSFORMS`.
+    #[must_use]
+    pub const fn right_transversal(self) -> Self {
+        let mut result = Self::EMPTY;
+        let mut covered = Self::EMPTY;
+        let mut i = 0;
+        while i < ALL_TRANSFORMS.len() {
+            if !covered.contains(ALL_TRANSFORMS[i]) {
+                result = result.with(ALL_TRANSFORMS[i]);
+                covered = covered.union(self.right_coset(ALL_TRANSFORMS[i]));
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns the set of all products `a * b` with `a` in `self` and `b` in `other`.
+    const fn products(self, other: Self) -> Self {
+        let mut result = Self::EMPTY;
+        let mut i = 0;
+        while i < ALL_TRANSFORMS.len() {
+            let mut j = 0;
+            while j < ALL_TRANSFORMS.len() {
+                if self.contains(ALL_TRANSFORMS[i]) && other.contains(ALL_TRANSFORMS[j]) {
+                    result = result.with(ALL_TRANSFORMS[i].compose(ALL_TRANSFORMS[j]));
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns the bit representing a `Transform`.
+    const fn bit(transform: Transform) -> u8 {
+        1 << transform as u8
+    }
+}
+
+impl Iterator for TransformSetIter {
+    type Item = Transform;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bits = self.remaining.bits();
+        if bits == 0 {
+            return None;
+        }
+        let transform = ALL_TRANSFORMS[bits.trailing_zeros() as usize];
+        self.remaining = self.remaining.without(transform);
+        Some(transform)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for TransformSetIter {}
+
+impl FusedIterator for TransformSetIter {}
+
+impl IntoIterator for TransformSet {
+    type Item = Transform;
+    type IntoIter = TransformSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Transform> for TransformSet {
+    fn from_iter<I: IntoIterator<Item = Transform>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+impl Extend<Transform> for TransformSet {
+    fn extend<I: IntoIterator<Item = Transform>>(&mut self, iter: I) {
+        *self = iter.into_iter().fold(*self, Self::with);
+    }
+}
+
+impl From<Transform> for TransformSet {
+    fn from(transform: Transform) -> Self {
+        Self::EMPTY.with(transform)
+    }
+}
+
+impl From<u8> for TransformSet {
+    fn from(bits: u8) -> Self {
+        Self::from_bits(bits)
+    }
+}
+
+impl From<TransformSet> for u8 {
+    fn from(set: TransformSet) -> Self {
+        set.bits()
+    }
+}
+
+impl BitOr for TransformSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for TransformSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl BitAnd for TransformSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for TransformSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitXor for TransformSet {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self::from_bits(self.bits ^ rhs.bits)
+    }
+}
+
+impl BitXorAssign for TransformSet {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl Sub for TransformSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for TransformSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Not for TransformSet {
+    type Output = Self;
+
+    /// Returns the complement of the set within D4.
+    fn not(self) -> Self::Output {
+        Self::from_bits(!self.bits)
+    }
+}
+
+impl Mul<Transform> for TransformSet {
+    type Output = Self;
+
+    /// Returns the right coset `self * rhs`.
+    fn mul(self, rhs: Transform) -> Self::Output {
+        self.right_coset(rhs)
+    }
+}
+
+impl Mul<TransformSet> for Transform {
+    type Output = TransformSet;
+
+    /// Returns the left coset `self * rhs`.
+    fn mul(self, rhs: TransformSet) -> Self::Output {
+        rhs.left_coset(self)
+    }
+}
+
+impl Display for TransformSet {
+    /// Writes the symbols of the members in the order of `ALL_TRANSFORMS`, such as `"IU|-"`.
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        self.iter().try_for_each(|transform| write!(fmt, "{transform}"))
+    }
+}
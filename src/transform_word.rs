@@ -0,0 +1,125 @@
+//! ## `transform_word`
+//!
+//! The `transform_word` module provides the `TransformWord` struct, a sequence of `Transform`s written as a string of
+//! their symbols.
+
+use alloc::vec::Vec;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    slice::Iter,
+    str::FromStr,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ParseError, Transform};
+
+/// A sequence of `Transform`s, such as `"RR|/"`, which retains every symbol rather than only their product.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TransformWord {
+    /// The `Transform`s in the order they were written.
+    transforms: Vec<Transform>,
+}
+
+impl TransformWord {
+    /// Constructs a new, empty `TransformWord`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { transforms: Vec::new() }
+    }
+
+    /// Returns the `Transform`s of the word in the order they were written.
+    #[must_use]
+    pub fn transforms(&self) -> &[Transform] {
+        &self.transforms
+    }
+
+    /// Returns the number of `Transform`s in the word.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.transforms.len()
+    }
+
+    /// Returns true if the word contains no `Transform`s.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.transforms.is_empty()
+    }
+
+    /// Appends a `Transform` to the end of the word.
+    pub fn push(&mut self, transform: Transform) {
+        self.transforms.push(transform);
+    }
+
+    /// Returns an iterator over the `Transform`s of the word.
+    pub fn iter(&self) -> Iter<'_, Transform> {
+        self.transforms.iter()
+    }
+
+    /// Returns the product of the word, multiplying its `Transform`s together from left to right.
+    ///
+    /// The `Display` of the result is the canonical single-symbol form of the word.
+    #[must_use]
+    pub fn reduce(&self) -> Transform {
+        self.iter()
+            .fold(Transform::Identity, |product, &transform| product * transform)
+    }
+}
+
+impl From<Vec<Transform>> for TransformWord {
+    fn from(transforms: Vec<Transform>) -> Self {
+        Self { transforms }
+    }
+}
+
+impl From<TransformWord> for Vec<Transform> {
+    fn from(word: TransformWord) -> Self {
+        word.transforms
+    }
+}
+
+impl FromIterator<Transform> for TransformWord {
+    fn from_iter<I: IntoIterator<Item = Transform>>(iter: I) -> Self {
+        Self {
+            transforms: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Transform> for TransformWord {
+    fn extend<I: IntoIterator<Item = Transform>>(&mut self, iter: I) {
+        self.transforms.extend(iter);
+    }
+}
+
+impl<'a> IntoIterator for &'a TransformWord {
+    type Item = &'a Transform;
+    type IntoIter = Iter<'a, Transform>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromStr for TransformWord {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.char_indices()
+            .enumerate()
+            .map(|(position, (offset, symbol))| {
+                Transform::from_symbol(symbol).ok_or_else(|| ParseError::InvalidTransformWord {
+                    symbol: s[offset..offset + symbol.len_utf8()].into(),
+                    position,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for TransformWord {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        self.iter().try_for_each(|transform| write!(fmt, "{transform}"))
+    }
+}
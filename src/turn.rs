@@ -0,0 +1,157 @@
+//! ## `turn`
+//!
+//! The `turn` module provides the `Turn` enum, which represents a change of heading relative to the current
+//! `Direction`.
+
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Add, AddAssign, Neg, Sub},
+    str::FromStr,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ConversionError, Direction, ParseError, Transform};
+
+/// All turns in their order of definition.
+pub const ALL_TURNS: [Turn; 4] = [
+    Turn::Straight,
+    Turn::Right,
+    Turn::Back,
+    Turn::Left, //
+];
+
+/// A relative change of heading, measured in clockwise quarter turns.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum Turn {
+    /// Keep the current heading
+    Straight = 0,
+    /// Turn a quarter clockwise
+    Right = 1,
+    /// Turn about to face the opposite way
+    Back = 2,
+    /// Turn a quarter anti-clockwise
+    Left = 3,
+}
+
+impl Add for Turn {
+    type Output = Self;
+
+    /// Returns the `Turn` equivalent to making `self` and then `rhs`.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_quarters(u8::from(self) + u8::from(rhs))
+    }
+}
+
+impl AddAssign for Turn {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Neg for Turn {
+    type Output = Self;
+
+    /// Returns the `Turn` which undoes `self`.
+    fn neg(self) -> Self::Output {
+        Self::from_quarters(4 - u8::from(self))
+    }
+}
+
+impl Turn {
+    /// Returns the `Turn` of the given number of clockwise quarter turns, modulo a full turn.
+    const fn from_quarters(quarters: u8) -> Self {
+        match quarters % 4 {
+            0 => Self::Straight,
+            1 => Self::Right,
+            2 => Self::Back,
+            _ => Self::Left,
+        }
+    }
+}
+
+impl Add<Turn> for Direction {
+    type Output = Self;
+
+    /// Returns the `Direction` faced after making the `Turn`.
+    ///
+    /// # Panics
+    ///
+    /// This function uses `unwrap()` internally but will never panic because the
+    /// result is reduced modulo four, so is always a valid `Direction` value (0-3).
+    fn add(self, rhs: Turn) -> Self::Output {
+        Self::try_from((u8::from(self) + u8::from(rhs)) % 4).unwrap()
+    }
+}
+
+impl AddAssign<Turn> for Direction {
+    fn add_assign(&mut self, rhs: Turn) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Direction {
+    type Output = Turn;
+
+    /// Returns the `Turn` which brings `rhs` to face `self`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Turn::from_quarters(u8::from(self) + 4 - u8::from(rhs))
+    }
+}
+
+impl From<Turn> for Transform {
+    /// Returns the rotation which turns every `Direction` by the `Turn`.
+    fn from(turn: Turn) -> Self {
+        match turn {
+            Turn::Straight => Self::Identity,
+            Turn::Right => Self::Rotate90,
+            Turn::Back => Self::Rotate180,
+            Turn::Left => Self::Rotate270,
+        }
+    }
+}
+
+impl TryFrom<Transform> for Turn {
+    type Error = ConversionError;
+
+    fn try_from(transform: Transform) -> Result<Self, Self::Error> {
+        match transform {
+            Transform::Identity => Ok(Self::Straight),
+            Transform::Rotate90 => Ok(Self::Right),
+            Transform::Rotate180 => Ok(Self::Back),
+            Transform::Rotate270 => Ok(Self::Left),
+            _ => Err(ConversionError::FlipTransform(transform)),
+        }
+    }
+}
+
+impl FromStr for Turn {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            ("F", "STRAIGHT", Self::Straight),
+            ("R", "RIGHT", Self::Right),
+            ("B", "BACK", Self::Back),
+            ("L", "LEFT", Self::Left),
+        ]
+        .into_iter()
+        .find(|(short, long, _)| s.eq_ignore_ascii_case(short) || s.eq_ignore_ascii_case(long))
+        .map(|(_, _, turn)| turn)
+        .ok_or_else(|| ParseError::InvalidTurn(s.into()))
+    }
+}
+
+impl Display for Turn {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        match *self {
+            Self::Straight => write!(fmt, "F"),
+            Self::Right => write!(fmt, "R"),
+            Self::Back => write!(fmt, "B"),
+            Self::Left => write!(fmt, "L"),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "array")]
+
+use nav::{ALL_TRANSFORMS, Transform};
+use ndarray::{Array2, arr2};
+use std::collections::HashSet;
+
+#[test]
+fn test_canonicalize_example() {
+    let arr = arr2(&[[3, 1], [4, 2]]);
+    let (canonical, transform) = Transform::canonicalize(&arr);
+    assert_eq!(canonical, arr2(&[[1, 2], [3, 4]]));
+    assert_eq!(transform, Transform::Rotate270);
+}
+
+#[test]
+fn test_canonicalize_is_invariant_under_transforms() {
+    let arr = Array2::from_shape_fn((3, 4), |(r, c)| (r * 7 + c * 3) % 5);
+    let (canonical, _) = Transform::canonicalize(&arr);
+    for transform in ALL_TRANSFORMS {
+        let (other, other_transform) = Transform::canonicalize(&(transform * &arr));
+        assert_eq!(other, canonical, "failed: {:?}", transform);
+        assert_eq!(other_transform * &(transform * &arr), canonical);
+    }
+}
+
+#[test]
+fn test_canonicalize_matches_brute_force() {
+    for bits in 0..(1 << 6) {
+        let arr = Array2::from_shape_fn((2, 3), |(r, c)| (bits >> (r * 3 + c)) & 1);
+        let (canonical, transform) = Transform::canonicalize(&arr);
+
+        let expected = ALL_TRANSFORMS
+            .into_iter()
+            .map(|t| {
+                let out = t * &arr;
+                (out.dim(), out.iter().copied().collect::<Vec<_>>())
+            })
+            .min()
+            .unwrap();
+        assert_eq!((canonical.dim(), canonical.iter().copied().collect::<Vec<_>>()), expected);
+        assert_eq!(transform * &arr, canonical);
+
+        // Ties resolve to the first transform producing the canonical orientation.
+        let first = ALL_TRANSFORMS.into_iter().find(|&t| t * &arr == canonical).unwrap();
+        assert_eq!(transform, first);
+    }
+}
+
+#[test]
+fn test_canonicalize_prefers_fewer_rows() {
+    let arr = Array2::from_shape_fn((4, 2), |(r, c)| r * 2 + c);
+    let (canonical, _) = Transform::canonicalize(&arr);
+    assert_eq!(canonical.dim(), (2, 4));
+}
+
+#[test]
+fn test_canonicalize_deduplicates_patterns() {
+    // Of the 512 3x3 binary patterns, 102 are distinct up to rotation and reflection.
+    let mut classes = HashSet::new();
+    for bits in 0..(1 << 9) {
+        let arr = Array2::from_shape_fn((3, 3), |(r, c)| (bits >> (r * 3 + c)) & 1);
+        let _ = classes.insert(Transform::canonicalize(&arr).0);
+    }
+    assert_eq!(classes.len(), 102);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "array")]
+
+use nav::{ALL_TRANSFORMS, Transform};
+use ndarray::{Array2, arr2};
+
+#[test]
+fn test_orbit_of_asymmetric_array() {
+    let arr = arr2(&[[1, 2], [3, 4]]);
+    let orbit: Vec<_> = Transform::orbit(&arr).collect();
+    assert_eq!(orbit.len(), 8);
+    for ((transform, orientation), expected) in orbit.into_iter().zip(ALL_TRANSFORMS) {
+        assert_eq!(transform, expected);
+        assert_eq!(orientation, expected * &arr);
+    }
+}
+
+#[test]
+fn test_orbit_of_symmetric_arrays() {
+    let uniform = Array2::from_elem((3, 3), 0);
+    let orbit: Vec<_> = Transform::orbit(&uniform).collect();
+    assert_eq!(orbit, [(Transform::Identity, uniform.clone())]);
+
+    let s_tile = arr2(&[[0, 1, 1], [0, 1, 0], [1, 1, 0]]);
+    let transforms: Vec<_> = Transform::orbit(&s_tile).map(|(transform, _)| transform).collect();
+    assert_eq!(
+        transforms,
+        [
+            Transform::Identity,
+            Transform::Rotate90,
+            Transform::FlipHorizontal,
+            Transform::FlipDiagonal
+        ]
+    );
+
+    let bar = arr2(&[[1, 1, 1]]);
+    let orbit: Vec<_> = Transform::orbit(&bar).collect();
+    assert_eq!(
+        orbit,
+        [
+            (Transform::Identity, bar.clone()),
+            (Transform::Rotate90, arr2(&[[1], [1], [1]]))
+        ]
+    );
+}
+
+#[test]
+fn test_orbit_yields_each_orientation_once() {
+    for bits in 0..(1 << 9) {
+        let arr = Array2::from_shape_fn((3, 3), |(r, c)| (bits >> (r * 3 + c)) & 1);
+        let orbit: Vec<_> = Transform::orbit(&arr).collect();
+        let stabilizer = Transform::stabilizer(&arr);
+
+        // Orbit-stabilizer theorem
+        assert_eq!(orbit.len() * stabilizer.len(), 8);
+        assert_eq!(Transform::orbit(&arr).len(), orbit.len());
+
+        // Every orientation appears exactly once, produced by the first transform yielding it.
+        for transform in ALL_TRANSFORMS {
+            let orientation = transform * &arr;
+            let matches: Vec<_> = orbit.iter().filter(|(_, o)| *o == orientation).collect();
+            assert_eq!(matches.len(), 1);
+            let first = ALL_TRANSFORMS.into_iter().find(|&t| t * &arr == orientation).unwrap();
+            assert_eq!(matches[0].0, first);
+        }
+    }
+}
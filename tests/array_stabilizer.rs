@@ -0,0 +1,70 @@
+#![cfg(feature = "array")]
+
+use nav::{ALL_SUBGROUPS, ALL_TRANSFORMS, Transform, TransformSet};
+use ndarray::{Array2, arr2};
+
+#[test]
+fn test_stabilizer_of_asymmetric_array() {
+    let arr = arr2(&[[1, 2], [3, 4]]);
+    assert_eq!(Transform::stabilizer(&arr), TransformSet::TRIVIAL);
+}
+
+#[test]
+fn test_stabilizer_of_uniform_array() {
+    let arr = Array2::from_elem((3, 3), 7);
+    assert_eq!(Transform::stabilizer(&arr), TransformSet::ALL);
+
+    // Non-square arrays can never be fixed by a transform which swaps their axes.
+    let arr = Array2::from_elem((2, 3), 7);
+    assert_eq!(
+        Transform::stabilizer(&arr),
+        TransformSet::generated_by(&[Transform::FlipHorizontal, Transform::FlipVertical])
+    );
+}
+
+#[test]
+fn test_stabilizer_examples() {
+    // Half-turn symmetry only
+    let s_tile = arr2(&[[0, 1, 1], [0, 1, 0], [1, 1, 0]]);
+    assert_eq!(
+        Transform::stabilizer(&s_tile),
+        TransformSet::generated_by(&[Transform::Rotate180])
+    );
+
+    // Quarter-turn symmetry only
+    let pinwheel = arr2(&[[1, 1, 0, 1], [0, 0, 0, 1], [1, 0, 0, 0], [1, 0, 1, 1]]);
+    assert_eq!(Transform::stabilizer(&pinwheel), TransformSet::ROTATIONS);
+
+    // Mirror symmetry about the vertical axis
+    let arrow = arr2(&[[0, 1, 0], [1, 1, 1], [0, 1, 0], [0, 1, 0]]);
+    assert_eq!(
+        Transform::stabilizer(&arrow),
+        TransformSet::generated_by(&[Transform::FlipHorizontal])
+    );
+
+    // Symmetric about the main diagonal
+    let symmetric = arr2(&[[1, 2, 3], [2, 4, 5], [3, 5, 6]]);
+    assert_eq!(
+        Transform::stabilizer(&symmetric),
+        TransformSet::generated_by(&[Transform::FlipDiagonal])
+    );
+}
+
+#[test]
+fn test_stabilizer_matches_allocating_mul() {
+    // Exhaustively check every 3x3 binary pattern.
+    for bits in 0..(1 << 9) {
+        let arr = Array2::from_shape_fn((3, 3), |(r, c)| (bits >> (r * 3 + c)) & 1);
+        let stabilizer = Transform::stabilizer(&arr);
+        for transform in ALL_TRANSFORMS {
+            assert_eq!(stabilizer.contains(transform), transform * &arr == arr);
+        }
+        assert!(ALL_SUBGROUPS.contains(&stabilizer));
+    }
+}
+
+#[test]
+fn test_stabilizer_of_empty_array() {
+    let arr = Array2::<u8>::zeros((0, 0));
+    assert_eq!(Transform::stabilizer(&arr), TransformSet::ALL);
+}
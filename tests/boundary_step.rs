@@ -0,0 +1,152 @@
+use nav::{ALL_DIRECTIONS, ALL_OCTANTS, Boundary, Coord, Direction, Octant, Transform};
+
+const DIM: (usize, usize) = (3, 4);
+
+#[test]
+fn test_boundary_interior_steps_agree() {
+    for boundary in [Boundary::Bounded, Boundary::Wrap, Boundary::Reflect, Boundary::Clamp] {
+        for octant in ALL_OCTANTS {
+            let expected = Boundary::Bounded.step(DIM, (1, 1), octant);
+            assert_eq!(boundary.step(DIM, (1, 1), octant), expected);
+            assert!(expected.is_some());
+        }
+    }
+    assert_eq!(Boundary::default(), Boundary::Bounded);
+}
+
+#[test]
+fn test_boundary_bounded() {
+    assert_eq!(Boundary::Bounded.step(DIM, (0, 2), Direction::North), None);
+    assert_eq!(Boundary::Bounded.step(DIM, (2, 3), Direction::East), None);
+    assert_eq!(Boundary::Bounded.step(DIM, (2, 1), Octant::SouthWest), None);
+    assert_eq!(
+        Boundary::Bounded.step(DIM, (1, 2), Direction::South),
+        Some(((2, 2), Direction::South))
+    );
+}
+
+#[test]
+fn test_boundary_wrap() {
+    let cases = [
+        ((0, 2), Octant::North, (2, 2)),
+        ((2, 2), Octant::South, (0, 2)),
+        ((1, 0), Octant::West, (1, 3)),
+        ((1, 3), Octant::East, (1, 0)),
+        ((0, 3), Octant::NorthEast, (2, 0)),
+        ((0, 1), Octant::NorthWest, (2, 0)),
+    ];
+    for (pos, octant, expected) in cases {
+        assert_eq!(Boundary::Wrap.step(DIM, pos, octant), Some((expected, octant)));
+    }
+
+    // Walking the full length of an axis on a torus returns to the start.
+    for direction in ALL_DIRECTIONS {
+        let mut pos = (1, 2);
+        let steps = if direction.is_vertical() { DIM.0 } else { DIM.1 };
+        for _ in 0..steps {
+            pos = Boundary::Wrap.step(DIM, pos, direction).unwrap().0;
+        }
+        assert_eq!(pos, (1, 2));
+    }
+}
+
+#[test]
+fn test_boundary_reflect() {
+    assert_eq!(
+        Boundary::Reflect.step(DIM, (0, 2), Direction::North),
+        Some(((0, 2), Direction::North * Transform::FlipVertical))
+    );
+    assert_eq!(
+        Boundary::Reflect.step(DIM, (1, 3), Direction::East),
+        Some(((1, 3), Direction::West))
+    );
+    // Glancing off the top edge keeps the horizontal motion.
+    assert_eq!(
+        Boundary::Reflect.step(DIM, (0, 1), Octant::NorthEast),
+        Some(((0, 2), Octant::SouthEast))
+    );
+    // A corner reflects both components.
+    assert_eq!(
+        Boundary::Reflect.step(DIM, (0, 3), Octant::NorthEast),
+        Some(((0, 3), Octant::SouthWest))
+    );
+
+    // A ball bouncing along a row returns to the start facing the same way.
+    let mut state = ((1, 1), Direction::East);
+    for _ in 0..2 * DIM.1 {
+        state = Boundary::Reflect.step(DIM, state.0, state.1).unwrap();
+    }
+    assert_eq!(state, ((1, 1), Direction::East));
+}
+
+#[test]
+fn test_boundary_clamp() {
+    assert_eq!(
+        Boundary::Clamp.step(DIM, (0, 2), Direction::North),
+        Some(((0, 2), Direction::North))
+    );
+    assert_eq!(
+        Boundary::Clamp.step(DIM, (0, 1), Octant::NorthEast),
+        Some(((0, 2), Octant::NorthEast))
+    );
+    assert_eq!(
+        Boundary::Clamp.step(DIM, (2, 3), Octant::SouthEast),
+        Some(((2, 3), Octant::SouthEast))
+    );
+}
+
+#[test]
+fn test_boundary_outside_grid() {
+    for boundary in [Boundary::Bounded, Boundary::Wrap, Boundary::Reflect, Boundary::Clamp] {
+        assert_eq!(boundary.step(DIM, (3, 0), Direction::North), None);
+        assert_eq!(boundary.step(DIM, (0, 4), Direction::West), None);
+        assert_eq!(boundary.step((0, 0), (0, 0), Direction::East), None);
+    }
+}
+
+#[test]
+fn test_boundary_single_cell() {
+    for direction in ALL_DIRECTIONS {
+        assert_eq!(Boundary::Wrap.step((1, 1), (0, 0), direction), Some(((0, 0), direction)));
+        assert_eq!(Boundary::Reflect.step((1, 1), (0, 0), direction), Some(((0, 0), -direction)));
+    }
+}
+
+#[test]
+fn test_boundary_step_coord() {
+    // Coordinates have the y-axis pointing North, with the origin at the bottom-left cell.
+    assert_eq!(
+        Boundary::Bounded.step_coord(DIM, Coord::new(1, 0), Direction::North),
+        Some((Coord::new(1, 1), Direction::North))
+    );
+    assert_eq!(Boundary::Bounded.step_coord(DIM, Coord::new(1, 2), Direction::North), None);
+    assert_eq!(
+        Boundary::Wrap.step_coord(DIM, Coord::new(1, 2), Direction::North),
+        Some((Coord::new(1, 0), Direction::North))
+    );
+    assert_eq!(
+        Boundary::Wrap.step_coord(DIM, Coord::new(0, 1), Direction::West),
+        Some((Coord::new(3, 1), Direction::West))
+    );
+    assert_eq!(
+        Boundary::Reflect.step_coord(DIM, Coord::new(3, 2), Octant::NorthEast),
+        Some((Coord::new(3, 2), Octant::SouthWest))
+    );
+    assert_eq!(
+        Boundary::Clamp.step_coord(DIM, Coord::new(0, 0), Octant::SouthWest),
+        Some((Coord::new(0, 0), Octant::SouthWest))
+    );
+    assert_eq!(Boundary::Wrap.step_coord(DIM, Coord::new(4, 0), Direction::North), None);
+    assert_eq!(Boundary::Wrap.step_coord(DIM, Coord::new(0, -1), Direction::North), None);
+    assert_eq!(Boundary::Wrap.step_coord((0, 0), Coord::new(0, 0), Direction::North), None);
+}
+
+#[test]
+fn test_boundary_step_coord_matches_coord_arithmetic() {
+    for boundary in [Boundary::Bounded, Boundary::Wrap, Boundary::Reflect, Boundary::Clamp] {
+        for octant in ALL_OCTANTS {
+            let coord = Coord::new(1, 1);
+            assert_eq!(boundary.step_coord(DIM, coord, octant), Some((coord + octant, octant)));
+        }
+    }
+}
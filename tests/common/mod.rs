@@ -0,0 +1,65 @@
+//! Map helpers shared by the grid search and pathfinding tests and benchmarks.
+
+#![allow(dead_code, reason = "Each test crate uses a different subset of the helpers.")]
+
+use nav::{Coord, Grid, Move, Octant};
+
+/// Parses a map of `.` floor, `#` wall and digit cells, where a digit is the cost of entering the cell.
+pub fn map(rows: &[&str]) -> Grid<char> {
+    let cells: Vec<char> = rows.iter().flat_map(|row| row.chars()).collect();
+    Grid::from_vec((rows.len(), rows[0].len()), cells).unwrap()
+}
+
+/// Returns the cost of entering a cell of a map.
+pub fn cost(&cell: &char) -> Option<f64> {
+    match cell {
+        '.' => Some(1.0),
+        '#' => None,
+        digit => digit.to_digit(10).map(f64::from),
+    }
+}
+
+/// Returns true if a cell of a map is passable.
+pub fn floor(&cell: &char) -> bool {
+    cell != '#'
+}
+
+/// Returns a cost of 1 for entering any passable cell of a map.
+pub fn unit(cell: &char) -> Option<f64> {
+    floor(cell).then_some(1.0)
+}
+
+/// Advances a linear congruential generator, returning its next 31-bit value.
+pub fn lcg(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+/// A deterministic pseudo-random map with `walls` cells in eight walled, and the rest costing 1 to 4.
+pub fn random_map(seed: u64, dim: (usize, usize), walls: u64) -> Grid<char> {
+    let mut state = seed;
+    Grid::from_fn(dim, |_| match lcg(&mut state) % 8 {
+        value if value < walls => '#',
+        value => char::from_digit(u32::try_from(value).unwrap() / 2 + 1, 10).unwrap(),
+    })
+}
+
+/// Returns the cost of following a path under `cost`, or panics if it leaves the map, enters an impassable cell or
+/// cuts the corner of one.
+pub fn path_cost<M: Move>(grid: &Grid<char>, start: (usize, usize), path: &[M], cost: impl Fn(&char) -> Option<f64>) -> f64 {
+    let mut pos = start;
+    path.iter()
+        .map(|&step| {
+            let octant: Octant = step.into();
+            let Coord { x, y } = Coord::from(octant);
+            for side in [Coord::new(x, 0), Coord::new(0, y)].into_iter().filter_map(Coord::octant) {
+                let open = grid.step(pos, side).is_some_and(|cell| cost(&grid[cell]).is_some());
+                assert!(x == 0 || y == 0 || open, "path cuts a corner at {pos:?}");
+            }
+            pos = grid.step(pos, step).unwrap();
+            cost(&grid[pos]).unwrap() * step.length()
+        })
+        .sum()
+}
@@ -0,0 +1,151 @@
+use core::f64::consts::FRAC_PI_2;
+use nav::{ALL_DIRECTIONS, ALL_OCTANTS, ALL_TRANSFORMS, Convention, Coord, Direction, Octant, Transform, Winding, YAxis};
+
+/// Every combination of y-axis and winding.
+const CONVENTIONS: [Convention; 4] = [
+    Convention::new(YAxis::Up, Winding::CounterClockwise),
+    Convention::new(YAxis::Up, Winding::Clockwise),
+    Convention::new(YAxis::Down, Winding::CounterClockwise),
+    Convention::new(YAxis::Down, Winding::Clockwise),
+];
+
+/// Applies a matrix to a column vector.
+fn apply(matrix: [[i8; 2]; 2], (x, y): (i32, i32)) -> (i32, i32) {
+    let [[a, b], [c, d]] = matrix.map(|row| row.map(i32::from));
+    (a * x + b * y, c * x + d * y)
+}
+
+#[test]
+fn test_convention_defaults() {
+    assert_eq!(Convention::default(), Convention::MATH);
+    assert_eq!(Convention::MATH, CONVENTIONS[0]);
+    assert_eq!(Convention::SCREEN, CONVENTIONS[3]);
+    assert!(Convention::SCREEN.is_y_down() && Convention::SCREEN.is_clockwise());
+    assert!(!Convention::MATH.is_y_down() && !Convention::MATH.is_clockwise());
+}
+
+#[test]
+fn test_convention_default_methods() {
+    for direction in ALL_DIRECTIONS {
+        assert_eq!(direction.to_angle(), direction.to_angle_in(Convention::MATH));
+        assert_eq!(direction.to_vector::<i32>(), direction.to_vector_in(Convention::MATH));
+    }
+    for octant in ALL_OCTANTS {
+        assert_eq!(octant.to_vector::<i32>(), octant.to_vector_in(Convention::MATH));
+    }
+    for transform in ALL_TRANSFORMS {
+        assert_eq!(transform.to_matrix(), transform.to_matrix_in(Convention::MATH));
+    }
+}
+
+#[test]
+fn test_convention_vectors() {
+    let expected = [
+        (Convention::MATH, Direction::North, (0, 1)),
+        (Convention::MATH, Direction::East, (1, 0)),
+        (Convention::SCREEN, Direction::North, (0, -1)),
+        (Convention::SCREEN, Direction::East, (1, 0)),
+        (Convention::SCREEN, Direction::South, (0, 1)),
+    ];
+    for (convention, direction, vector) in expected {
+        assert_eq!(direction.to_vector_in::<i32>(convention), vector);
+    }
+    for convention in CONVENTIONS {
+        for direction in ALL_DIRECTIONS {
+            let vector = direction.to_vector_in::<i32>(convention);
+            assert_eq!(Octant::from(direction).to_vector_in::<i32>(convention), vector);
+            assert_eq!(Coord::from(vector).direction_in(convention), Some(direction));
+            assert_eq!(Coord::ZERO.step_in(direction, convention), Coord::from(vector));
+        }
+        for octant in ALL_OCTANTS {
+            let vector = octant.to_vector_in::<i32>(convention);
+            assert_eq!(Coord::from(vector).octant_in(convention), Some(octant));
+        }
+    }
+}
+
+#[test]
+fn test_convention_angles() {
+    for convention in CONVENTIONS {
+        // Angles agree with `atan2(dy, dx)` exactly when the winding turns from the x-axis towards the y-axis.
+        let atan2_sign = if convention.is_y_down() == convention.is_clockwise() {
+            1.0
+        } else {
+            -1.0
+        };
+        for direction in ALL_DIRECTIONS {
+            let angle = direction.to_angle_in(convention);
+            let (dx, dy) = direction.to_vector_in::<f64>(convention);
+            assert!(
+                (angle.cos() - dx).abs() < 1e-12,
+                "failed: {:?} in {:?}",
+                direction,
+                convention
+            );
+            assert!(
+                (atan2_sign * angle.sin() - dy).abs() < 1e-12,
+                "failed: {:?} in {:?}",
+                direction,
+                convention
+            );
+
+            for offset in [-0.7, 0.0, 0.7] {
+                assert_eq!(Direction::from_angle_nearest_in(angle + offset, convention), Some(direction));
+            }
+        }
+        // A quarter turn in the winding of the convention moves East to North or South.
+        let quarter = Direction::from_angle_nearest_in(FRAC_PI_2, convention);
+        let expected = if convention.is_clockwise() {
+            Direction::South
+        } else {
+            Direction::North
+        };
+        assert_eq!(quarter, Some(expected));
+    }
+}
+
+#[test]
+fn test_convention_matrices() {
+    assert_eq!(Transform::Rotate90.to_matrix_in(Convention::SCREEN), [[0, -1], [1, 0]]);
+    for convention in CONVENTIONS {
+        for transform in ALL_TRANSFORMS {
+            let matrix = transform.to_matrix_in(convention);
+            assert_eq!(Transform::from_matrix_in(matrix, convention), Some(transform));
+            for octant in ALL_OCTANTS {
+                assert_eq!(
+                    apply(matrix, octant.to_vector_in(convention)),
+                    (octant * transform).to_vector_in(convention),
+                    "failed: {:?} * {:?} in {:?}",
+                    octant,
+                    transform,
+                    convention
+                );
+            }
+            for a in ALL_TRANSFORMS {
+                let [[p, q], [r, s]] = a.to_matrix_in(convention);
+                let [[t, u], [v, w]] = transform.to_matrix_in(convention);
+                assert_eq!(
+                    (a * transform).to_matrix_in(convention),
+                    [[p * t + q * v, p * u + q * w], [r * t + s * v, r * u + s * w]]
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_convention_coord_transform() {
+    let coord = Coord::new(2, 5);
+    for convention in CONVENTIONS {
+        for transform in ALL_TRANSFORMS {
+            let expected = apply(transform.to_matrix_in(convention), coord.into());
+            assert_eq!(coord.transform_in(transform, convention), Coord::from(expected));
+        }
+        // `Rotate90` turns a step North into a step East in every frame.
+        let north = Coord::ZERO.step_in(Direction::North, convention);
+        assert_eq!(
+            north.transform_in(Transform::Rotate90, convention).direction_in(convention),
+            Some(Direction::East)
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use nav::{ConversionError, Direction, Octant, Transform, Turn};
+use std::error::Error;
+
+#[test]
+fn test_conversion_error_is_error() {
+    fn cardinal(octant: Octant) -> Result<Direction, Box<dyn Error>> {
+        Ok(Direction::try_from(octant)?)
+    }
+
+    assert_eq!(cardinal(Octant::West).unwrap(), Direction::West);
+
+    let err = cardinal(Octant::SouthWest).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ConversionError>(),
+        Some(&ConversionError::DiagonalOctant(Octant::SouthWest))
+    );
+}
+
+#[test]
+fn test_conversion_error_display() {
+    assert_eq!(
+        ConversionError::DiagonalOctant(Octant::NorthEast).to_string(),
+        "Diagonal octant NE has no equivalent direction"
+    );
+}
+
+#[test]
+fn test_conversion_error_direction_set_bits() {
+    assert_eq!(
+        ConversionError::InvalidDirectionSetBits(0b1_0011).to_string(),
+        "Invalid direction set bits 0b00010011, only the lowest four bits may be set"
+    );
+}
+
+#[test]
+fn test_conversion_error_flip_transform() {
+    assert_eq!(
+        Turn::try_from(Transform::FlipDiagonal),
+        Err(ConversionError::FlipTransform(Transform::FlipDiagonal))
+    );
+    assert_eq!(
+        ConversionError::FlipTransform(Transform::FlipDiagonal).to_string(),
+        "Flip transform / has no equivalent turn"
+    );
+}
+
+#[test]
+fn test_conversion_error_jump_table() {
+    assert_eq!(
+        ConversionError::InconsistentJumpTable.to_string(),
+        "Jump table does not match its dimensions"
+    );
+}
@@ -0,0 +1,74 @@
+use nav::{ALL_DIRECTIONS, ALL_OCTANTS, Coord, Direction, Octant};
+
+#[test]
+fn test_coord_add_direction() {
+    let origin = Coord::ZERO;
+    assert_eq!(origin + Direction::North, Coord::new(0, 1));
+    assert_eq!(origin + Direction::East, Coord::new(1, 0));
+    assert_eq!(origin + Direction::South, Coord::new(0, -1));
+    assert_eq!(origin + Direction::West, Coord::new(-1, 0));
+
+    let mut coord = Coord::new(3, 4);
+    coord += Direction::North;
+    coord += Direction::North;
+    coord += Direction::West;
+    assert_eq!(coord, Coord::new(2, 6));
+
+    coord -= Direction::North;
+    assert_eq!(coord, Coord::new(2, 5));
+}
+
+#[test]
+fn test_coord_add_octant() {
+    let mut coord = Coord::new(3, 4);
+    coord += Octant::NorthEast;
+    assert_eq!(coord, Coord::new(4, 5));
+    assert_eq!(coord + Octant::SouthWest, Coord::new(3, 4));
+}
+
+#[test]
+fn test_coord_sub() {
+    let a = Coord::new(5, -2);
+    let b = Coord::new(1, 3);
+    assert_eq!(a - b, Coord::new(4, -5));
+    assert_eq!(b + (a - b), a);
+    assert_eq!(-(a - b), b - a);
+
+    let mut c = a;
+    c -= b;
+    assert_eq!(c, Coord::new(4, -5));
+}
+
+#[test]
+fn test_coord_delta_to_direction() {
+    for direction in ALL_DIRECTIONS {
+        let start = Coord::new(7, -3);
+        assert_eq!(((start + direction) - start).direction(), Some(direction));
+    }
+
+    assert_eq!(Coord::ZERO.direction(), None);
+    assert_eq!(Coord::new(1, 1).direction(), None);
+    assert_eq!(Coord::new(0, 2).direction(), None);
+}
+
+#[test]
+fn test_coord_delta_to_octant() {
+    for octant in ALL_OCTANTS {
+        assert_eq!(Coord::from(octant).octant(), Some(octant));
+    }
+
+    assert_eq!(Coord::ZERO.octant(), None);
+    assert_eq!(Coord::new(2, 2).octant(), None);
+}
+
+#[test]
+fn test_coord_lengths() {
+    assert_eq!(Coord::new(3, -4).manhattan(), 7);
+    assert_eq!(Coord::new(3, -4).chebyshev(), 4);
+    assert_eq!(Coord::ZERO.manhattan(), 0);
+}
+
+#[test]
+fn test_coord_to_string() {
+    assert_eq!(Coord::new(3, -4).to_string(), "(3, -4)");
+}
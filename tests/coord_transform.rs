@@ -0,0 +1,80 @@
+use nav::{ALL_DIRECTIONS, ALL_OCTANTS, ALL_TRANSFORMS, Coord, Transform};
+
+const TEST_CASES: &[(Transform, (i32, i32))] = &[
+    (Transform::Identity, (2, 5)),
+    (Transform::Rotate90, (5, -2)),
+    (Transform::Rotate180, (-2, -5)),
+    (Transform::Rotate270, (-5, 2)),
+    (Transform::FlipHorizontal, (-2, 5)),
+    (Transform::FlipDiagonal, (5, 2)),
+    (Transform::FlipVertical, (2, -5)),
+    (Transform::FlipAntiDiagonal, (-5, -2)),
+];
+
+#[test]
+fn all_transforms_mul() {
+    let coord = Coord::new(2, 5);
+    for &(transform, expected) in TEST_CASES {
+        assert_eq!(coord * transform, Coord::from(expected), "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn all_transforms_mul_assign() {
+    for &(transform, expected) in TEST_CASES {
+        let mut tmp = Coord::new(2, 5);
+        tmp *= transform;
+        assert_eq!(tmp, Coord::from(expected), "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn transforms_match_direction() {
+    for direction in ALL_DIRECTIONS {
+        for transform in ALL_TRANSFORMS {
+            assert_eq!(
+                Coord::from(direction) * transform,
+                Coord::from(direction * transform),
+                "failed: {:?} * {:?}",
+                direction,
+                transform
+            );
+        }
+    }
+}
+
+#[test]
+fn transforms_match_octant() {
+    for octant in ALL_OCTANTS {
+        for transform in ALL_TRANSFORMS {
+            assert_eq!(
+                Coord::from(octant) * transform,
+                Coord::from(octant * transform),
+                "failed: {:?} * {:?}",
+                octant,
+                transform
+            );
+        }
+    }
+}
+
+#[test]
+fn transforms_are_linear() {
+    let a = Coord::new(3, -7);
+    let b = Coord::new(-1, 4);
+    for transform in ALL_TRANSFORMS {
+        assert_eq!((a + b) * transform, a * transform + b * transform);
+        assert_eq!((a - b) * transform, a * transform - b * transform);
+    }
+}
+
+#[test]
+fn composition_matches_transform_mul() {
+    let coord = Coord::new(2, 5);
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            // `Transform` composition applies the right-hand operand first.
+            assert_eq!(coord * (a * b), coord * b * a, "failed: {:?} * {:?}", a, b);
+        }
+    }
+}
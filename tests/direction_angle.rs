@@ -0,0 +1,70 @@
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+use nav::{ALL_DIRECTIONS, ALL_TURNS, Coord, Direction};
+
+#[test]
+fn test_direction_to_angle() {
+    assert_eq!(Direction::East.to_angle(), 0.0);
+    assert_eq!(Direction::North.to_angle(), FRAC_PI_2);
+    assert_eq!(Direction::West.to_angle(), PI);
+    assert_eq!(Direction::South.to_angle(), 3.0 * FRAC_PI_2);
+    assert_eq!(Direction::North.to_angle().to_degrees(), 90.0);
+    for direction in ALL_DIRECTIONS {
+        let angle = direction.to_angle();
+        assert!((0.0..TAU).contains(&angle));
+        let (dx, dy) = direction.to_vector::<f64>();
+        assert!((angle.cos() - dx).abs() < 1e-12 && (angle.sin() - dy).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_direction_from_angle_nearest() {
+    for direction in ALL_DIRECTIONS {
+        let angle = direction.to_angle();
+        for offset in [-0.7, -0.1, 0.0, 0.1, 0.7] {
+            for turns in [-3.0, -1.0, 0.0, 1.0, 5.0] {
+                let input = angle + offset + turns * TAU;
+                assert_eq!(Direction::from_angle_nearest(input), Some(direction), "failed: {input}");
+            }
+        }
+    }
+    assert_eq!(Direction::from_angle_nearest(-FRAC_PI_2), Some(Direction::South));
+    assert_eq!(Direction::from_angle_nearest(30.0_f64.to_radians()), Some(Direction::East));
+    assert_eq!(Direction::from_angle_nearest(60.0_f64.to_radians()), Some(Direction::North));
+}
+
+#[test]
+fn test_direction_from_angle_nearest_ties() {
+    // Exact halfway angles round counter-clockwise.
+    assert_eq!(Direction::from_angle_nearest(FRAC_PI_4), Some(Direction::North));
+    assert_eq!(Direction::from_angle_nearest(-FRAC_PI_4), Some(Direction::East));
+    assert_eq!(Direction::from_angle_nearest(3.0 * FRAC_PI_4), Some(Direction::West));
+}
+
+#[test]
+fn test_direction_from_angle_nearest_non_finite() {
+    assert_eq!(Direction::from_angle_nearest(f64::NAN), None);
+    assert_eq!(Direction::from_angle_nearest(f64::INFINITY), None);
+    assert_eq!(Direction::from_angle_nearest(f64::NEG_INFINITY), None);
+}
+
+#[test]
+fn test_direction_to_vector() {
+    assert_eq!(Direction::North.to_vector::<i32>(), (0, 1));
+    assert_eq!(Direction::East.to_vector::<i8>(), (1, 0));
+    assert_eq!(Direction::South.to_vector::<i64>(), (0, -1));
+    assert_eq!(Direction::West.to_vector::<f32>(), (-1.0, 0.0));
+    for direction in ALL_DIRECTIONS {
+        assert_eq!(Coord::from(direction.to_vector::<i32>()), Coord::from(direction));
+    }
+}
+
+#[test]
+fn test_direction_angle_turns() {
+    // A right turn is a clockwise quarter turn, so decreases the angle.
+    for direction in ALL_DIRECTIONS {
+        for turn in ALL_TURNS {
+            let expected = direction.to_angle() - f64::from(u8::from(turn)) * FRAC_PI_2;
+            assert_eq!(Direction::from_angle_nearest(expected), Some(direction + turn));
+        }
+    }
+}
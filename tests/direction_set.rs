@@ -0,0 +1,128 @@
+use nav::{ALL_DIRECTIONS, ALL_TRANSFORMS, ConversionError, Direction, DirectionSet, ParseError, Transform};
+
+#[test]
+fn test_direction_set_membership() {
+    let mut set = DirectionSet::EMPTY;
+    assert!(set.is_empty());
+    assert!(set.insert(Direction::East));
+    assert!(!set.insert(Direction::East));
+    assert!(set.insert(Direction::North));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(Direction::North));
+    assert!(!set.contains(Direction::South));
+    assert!(set.remove(Direction::North));
+    assert!(!set.remove(Direction::North));
+    assert_eq!(set, DirectionSet::from(Direction::East));
+}
+
+#[test]
+fn test_direction_set_operations() {
+    let ne = DirectionSet::from_directions(&[Direction::North, Direction::East]);
+    assert_eq!(
+        ne | DirectionSet::VERTICAL,
+        DirectionSet::from_directions(&[Direction::North, Direction::East, Direction::South])
+    );
+    assert_eq!(ne & DirectionSet::VERTICAL, Direction::North.into());
+    assert_eq!(ne - DirectionSet::VERTICAL, Direction::East.into());
+    assert_eq!(
+        ne ^ DirectionSet::ALL,
+        DirectionSet::from_directions(&[Direction::South, Direction::West])
+    );
+    assert_eq!(!ne, ne ^ DirectionSet::ALL);
+    assert_eq!(!DirectionSet::VERTICAL, DirectionSet::HORIZONTAL);
+    assert_eq!(!DirectionSet::ALL, DirectionSet::EMPTY);
+    assert!(DirectionSet::VERTICAL.is_subset(DirectionSet::ALL));
+    assert!(!ne.is_subset(DirectionSet::VERTICAL));
+}
+
+#[test]
+fn test_direction_set_iteration_order() {
+    let set = DirectionSet::from_directions(&[Direction::West, Direction::North, Direction::South]);
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        [Direction::North, Direction::South, Direction::West]
+    );
+    assert_eq!(set.iter().len(), 3);
+    assert_eq!(DirectionSet::ALL.into_iter().collect::<Vec<_>>(), ALL_DIRECTIONS);
+    assert_eq!(ALL_DIRECTIONS.into_iter().collect::<DirectionSet>(), DirectionSet::ALL);
+}
+
+#[test]
+fn test_direction_set_to_from_u8() {
+    for bits in 0..16_u8 {
+        let set = DirectionSet::from_bits(bits).unwrap();
+        assert_eq!(u8::from(set), bits);
+        assert_eq!(DirectionSet::try_from(bits), Ok(set));
+        for direction in ALL_DIRECTIONS {
+            assert_eq!(set.contains(direction), bits & (1 << u8::from(direction)) != 0);
+        }
+    }
+    for bits in 16..=u8::MAX {
+        assert_eq!(DirectionSet::from_bits(bits), None);
+        assert_eq!(
+            DirectionSet::try_from(bits),
+            Err(ConversionError::InvalidDirectionSetBits(bits))
+        );
+    }
+}
+
+#[test]
+fn test_direction_set_transform() {
+    let corner = DirectionSet::from_directions(&[Direction::North, Direction::East]);
+    assert_eq!(
+        corner * Transform::Rotate90,
+        DirectionSet::from_directions(&[Direction::East, Direction::South])
+    );
+    assert_eq!(
+        corner * Transform::FlipHorizontal,
+        DirectionSet::from_directions(&[Direction::North, Direction::West])
+    );
+
+    for bits in 0..16 {
+        let set = DirectionSet::from_bits(bits).unwrap();
+        for transform in ALL_TRANSFORMS {
+            let mut transformed = set;
+            transformed *= transform;
+            assert_eq!(transformed.len(), set.len());
+            for direction in set {
+                assert!(transformed.contains(direction * transform));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_direction_set_to_from_str() {
+    for bits in 0..16 {
+        let set = DirectionSet::from_bits(bits).unwrap();
+        assert_eq!(set.to_string().parse::<DirectionSet>(), Ok(set));
+    }
+    assert_eq!(DirectionSet::ALL.to_string(), "NESW");
+    assert_eq!(DirectionSet::HORIZONTAL.to_string(), "EW");
+    assert_eq!(DirectionSet::EMPTY.to_string(), "");
+    assert_eq!(
+        "wn".parse(),
+        Ok(DirectionSet::from_directions(&[Direction::North, Direction::West]))
+    );
+    assert_eq!(
+        "NNE".parse(),
+        Ok(DirectionSet::from_directions(&[Direction::North, Direction::East]))
+    );
+}
+
+#[test]
+fn test_direction_set_from_str_error() {
+    let err = "NEX".parse::<DirectionSet>().unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::InvalidDirectionSet {
+            symbol: "X".into(),
+            position: 2
+        }
+    );
+    assert_eq!(err.position(), Some(2));
+    assert_eq!(
+        err.to_string(),
+        "Invalid direction symbol \"X\" at position 2, expected one of: N, E, S, W"
+    );
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "serde")]
+
+use nav::{Direction, DirectionSet};
+use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+#[test]
+fn test_direction_set_serde_round_trip() {
+    let set = DirectionSet::from_directions(&[Direction::North, Direction::West]);
+    assert_tokens(&set, &[Token::U8(0b1001)]);
+    assert_tokens(&DirectionSet::EMPTY, &[Token::U8(0)]);
+    assert_tokens(&DirectionSet::ALL, &[Token::U8(0b1111)]);
+}
+
+#[test]
+fn test_direction_set_serde_rejects_invalid_bits() {
+    assert_de_tokens_error::<DirectionSet>(
+        &[Token::U8(255)],
+        "Invalid direction set bits 0b11111111, only the lowest four bits may be set",
+    );
+    assert_de_tokens_error::<DirectionSet>(
+        &[Token::U8(0b1_0000)],
+        "Invalid direction set bits 0b00010000, only the lowest four bits may be set",
+    );
+}
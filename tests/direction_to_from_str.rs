@@ -1,4 +1,4 @@
-use nav::{ALL_DIRECTIONS, Direction};
+use nav::{ALL_DIRECTIONS, Direction, ParseError};
 use std::str::FromStr;
 
 #[test]
@@ -44,9 +44,12 @@ fn test_direction_from_str_error() {
     assert!(Direction::from_str("123").is_err());
 
     let err = Direction::from_str("Invalid").unwrap_err();
+    assert_eq!(err, ParseError::InvalidDirection("Invalid".into()));
+    assert_eq!(err.input(), "Invalid");
+    assert_eq!(err.expected(), "N, E, S, W, North, East, South, West");
     assert_eq!(
-        err,
-        "Invalid direction, expected one of: N, E, S, W, North, East, South, West"
+        err.to_string(),
+        "Invalid direction \"Invalid\", expected one of: N, E, S, W, North, East, South, West"
     );
 }
 
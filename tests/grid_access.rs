@@ -0,0 +1,121 @@
+#![cfg(feature = "alloc")]
+
+use nav::{Coord, Direction, Grid};
+
+/// A 3x4 grid whose cells hold `row * 10 + col`.
+fn grid() -> Grid<usize> {
+    Grid::from_fn((3, 4), |(row, col)| row * 10 + col)
+}
+
+#[test]
+fn test_grid_construction() {
+    let grid = grid();
+    assert_eq!(grid.dim(), (3, 4));
+    assert_eq!((grid.rows(), grid.cols()), (3, 4));
+    assert_eq!(grid.len(), 12);
+    assert!(!grid.is_empty());
+    assert_eq!(Grid::from_vec((3, 4), grid.as_slice().to_vec()), Some(grid.clone()));
+    assert_eq!(Grid::from_vec((3, 3), vec![0; 12]), None);
+    assert_eq!(Grid::new((2, 2), 'x').as_slice(), ['x'; 4]);
+    assert!(Grid::<u8>::new((0, 5), 0).is_empty());
+}
+
+#[test]
+fn test_grid_indexing() {
+    let mut grid = grid();
+    assert_eq!(grid[(2, 3)], 23);
+    assert_eq!(grid.get((1, 2)), Some(&12));
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.get((0, 4)), None);
+    assert!(grid.contains((2, 3)));
+    assert!(!grid.contains((2, 4)));
+
+    grid[(0, 0)] = 99;
+    *grid.get_mut((1, 1)).unwrap() = 98;
+    assert_eq!(grid.get_mut((5, 5)), None);
+    assert_eq!(&grid.as_slice()[..6], [99, 1, 2, 3, 10, 98]);
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds")]
+fn test_grid_index_out_of_bounds() {
+    let _ = grid()[(3, 0)];
+}
+
+#[test]
+fn test_grid_coord_indexing() {
+    let mut grid = grid();
+    // The y-axis of a `Coord` points North, so the origin is the bottom-left cell.
+    assert_eq!(grid.position(Coord::new(0, 0)), Some((2, 0)));
+    assert_eq!(grid.position(Coord::new(3, 2)), Some((0, 3)));
+    assert_eq!(grid.position(Coord::new(4, 0)), None);
+    assert_eq!(grid.position(Coord::new(0, 3)), None);
+    assert_eq!(grid.position(Coord::new(-1, 0)), None);
+    assert_eq!(grid.coord((2, 0)), Some(Coord::new(0, 0)));
+    assert_eq!(grid.coord((3, 0)), None);
+    assert_eq!(grid[Coord::new(1, 2)], 1);
+
+    // Stepping a `Coord` agrees with stepping its position.
+    for (pos, _) in grid.indexed_iter() {
+        let coord = grid.coord(pos).unwrap();
+        assert_eq!(grid.position(coord), Some(pos));
+        assert_eq!(grid.position(coord + Direction::North), grid.step(pos, Direction::North));
+    }
+
+    grid[Coord::new(2, 1)] = 99;
+    assert_eq!(grid[(1, 2)], 99);
+    assert_eq!(Grid::<u8>::new((0, 0), 0).position(Coord::new(0, 0)), None);
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds")]
+fn test_grid_coord_index_out_of_bounds() {
+    let _ = grid()[Coord::new(0, -1)];
+}
+
+#[test]
+fn test_grid_rows_and_columns() {
+    let mut grid = grid();
+    assert_eq!(grid.row(1), [10, 11, 12, 13]);
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [2, 12, 22]);
+    assert_eq!(grid.iter_rows().count(), 3);
+    assert_eq!(grid.iter_rows().last().unwrap(), [20, 21, 22, 23]);
+    assert_eq!(
+        grid.iter_columns()
+            .map(|column| column.copied().sum::<usize>())
+            .collect::<Vec<_>>(),
+        [30, 33, 36, 39]
+    );
+
+    grid.row_mut(0).fill(7);
+    assert_eq!(grid.row(0), [7; 4]);
+    assert!(Grid::<u8>::new((3, 0), 0).iter_rows().eq([[]; 3]));
+    assert_eq!(Grid::<u8>::new((3, 0), 0).iter_columns().count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Column out of bounds")]
+fn test_grid_column_out_of_bounds() {
+    let _ = grid().column(4);
+}
+
+#[test]
+fn test_grid_iteration() {
+    let mut grid = grid();
+    assert_eq!(grid.iter().count(), 12);
+    for ((row, col), cell) in grid.indexed_iter() {
+        assert_eq!(*cell, row * 10 + col);
+    }
+    for cell in &mut grid {
+        *cell += 1;
+    }
+    assert_eq!((&grid).into_iter().sum::<usize>(), 138 + 12);
+    assert_eq!(grid.into_vec()[11], 24);
+}
+
+#[test]
+fn test_grid_map() {
+    let grid = grid().map(|&cell| cell % 2 == 0);
+    assert_eq!(grid.dim(), (3, 4));
+    assert_eq!(grid.row(1), [true, false, true, false]);
+}
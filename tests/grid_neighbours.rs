@@ -0,0 +1,89 @@
+#![cfg(feature = "alloc")]
+
+use nav::{Boundary, Direction, Grid, Octant};
+
+/// A 3x3 grid whose cells hold their own position.
+fn grid() -> Grid<(usize, usize)> {
+    Grid::from_fn((3, 3), |pos| pos)
+}
+
+#[test]
+fn test_grid_step() {
+    let grid = grid();
+    assert_eq!(grid.step((1, 1), Direction::North), Some((0, 1)));
+    assert_eq!(grid.step((1, 1), Direction::East), Some((1, 2)));
+    assert_eq!(grid.step((1, 1), Direction::South), Some((2, 1)));
+    assert_eq!(grid.step((1, 1), Direction::West), Some((1, 0)));
+    assert_eq!(grid.step((1, 1), Octant::NorthEast), Some((0, 2)));
+    assert_eq!(grid.step((1, 1), Octant::SouthWest), Some((2, 0)));
+    assert_eq!(grid.step((0, 0), Direction::North), None);
+    assert_eq!(grid.step((0, 0), Direction::West), None);
+    assert_eq!(grid.step((2, 2), Octant::SouthEast), None);
+    assert_eq!(grid.step((3, 0), Direction::North), None);
+}
+
+#[test]
+fn test_grid_neighbour() {
+    let grid = grid();
+    assert_eq!(grid.neighbour((0, 0), Direction::East), Some(&(0, 1)));
+    assert_eq!(grid.neighbour((0, 0), Octant::SouthEast), Some(&(1, 1)));
+    assert_eq!(grid.neighbour((0, 0), Direction::North), None);
+}
+
+#[test]
+fn test_grid_neighbours4() {
+    let grid = grid();
+    assert_eq!(
+        grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+        [
+            (Direction::North, (0, 1), &(0, 1)),
+            (Direction::East, (1, 2), &(1, 2)),
+            (Direction::South, (2, 1), &(2, 1)),
+            (Direction::West, (1, 0), &(1, 0)),
+        ]
+    );
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(Direction::East, (0, 1), &(0, 1)), (Direction::South, (1, 0), &(1, 0)),]
+    );
+    assert_eq!(grid.neighbours4((5, 5)).count(), 0);
+}
+
+#[test]
+fn test_grid_neighbours8() {
+    let grid = grid();
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    for (octant, pos, cell) in grid.neighbours8((1, 1)) {
+        assert_eq!(grid.step((1, 1), octant), Some(pos));
+        assert_eq!(*cell, pos);
+    }
+    assert_eq!(
+        grid.neighbours8((2, 2)).map(|(octant, ..)| octant).collect::<Vec<_>>(),
+        [Octant::North, Octant::West, Octant::NorthWest]
+    );
+}
+
+#[test]
+fn test_grid_neighbours_with_boundary() {
+    let grid = grid();
+    assert_eq!(
+        grid.neighbours4_with((0, 0), Boundary::Wrap)
+            .map(|(_, pos, _)| pos)
+            .collect::<Vec<_>>(),
+        [(2, 0), (0, 1), (1, 0), (0, 2)]
+    );
+    assert_eq!(grid.neighbours8_with((0, 0), Boundary::Wrap).count(), 8);
+    assert_eq!(grid.neighbours8_with((0, 0), Boundary::Bounded).count(), 3);
+    assert_eq!(
+        grid.neighbours4_with((0, 0), Boundary::Clamp)
+            .map(|(_, pos, _)| pos)
+            .collect::<Vec<_>>(),
+        [(0, 0), (0, 1), (1, 0), (0, 0)]
+    );
+    assert_eq!(grid.neighbour_with((2, 2), Octant::SouthEast, Boundary::Wrap), Some(&(0, 0)));
+    assert_eq!(grid.neighbour_with((2, 2), Octant::SouthEast, Boundary::Bounded), None);
+    assert_eq!(
+        grid.step_with((2, 1), Direction::South, Boundary::Reflect),
+        Some(((2, 1), Direction::North))
+    );
+}
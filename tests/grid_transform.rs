@@ -0,0 +1,107 @@
+#![cfg(feature = "alloc")]
+
+use nav::{ALL_TRANSFORMS, Direction, Grid, Transform};
+
+/// A 2x3 grid whose cells hold `row * 10 + col`.
+fn grid() -> Grid<usize> {
+    Grid::from_fn((2, 3), |(row, col)| row * 10 + col)
+}
+
+#[test]
+fn test_grid_transform() {
+    let rotated = &grid() * Transform::Rotate90;
+    assert_eq!(rotated.dim(), (3, 2));
+    assert_eq!(rotated.as_slice(), [10, 0, 11, 1, 12, 2]);
+    assert_eq!(grid() * Transform::Identity, grid());
+}
+
+#[test]
+fn test_grid_transform_matches_apply_index() {
+    let grid = grid();
+    for transform in ALL_TRANSFORMS {
+        let transformed = &grid * transform;
+        assert_eq!(transformed.dim(), transform.apply_dims(grid.dim()));
+        for (pos, cell) in grid.indexed_iter() {
+            assert_eq!(transformed[transform.apply_index(grid.dim(), pos)], *cell);
+        }
+    }
+}
+
+#[test]
+fn test_grid_transform_composition() {
+    let grid = grid();
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            assert_eq!(&grid * (a * b), &grid * a * b, "failed: {:?} * {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_grid_transform_neighbours() {
+    // Transforming a grid moves its neighbour relations with it, with headings mapped by `index_heading`.
+    let grid = grid();
+    for transform in ALL_TRANSFORMS {
+        let transformed = &grid * transform;
+        let heading = transform.index_heading();
+        for (pos, cell) in grid.indexed_iter() {
+            let moved = transform.apply_index(grid.dim(), pos);
+            assert_eq!(transformed[moved], *cell);
+            for (octant, _, neighbour) in grid.neighbours8(pos) {
+                assert_eq!(
+                    transformed.neighbour(moved, octant * heading),
+                    Some(neighbour),
+                    "failed: {transform:?}"
+                );
+            }
+            for (direction, _, neighbour) in grid.neighbours4(pos) {
+                assert_eq!(
+                    transformed.neighbour(moved, direction * heading),
+                    Some(neighbour),
+                    "failed: {transform:?}"
+                );
+            }
+        }
+    }
+    let rotated = &grid * Transform::Rotate90;
+    assert_eq!(rotated.neighbour((0, 0), Direction::East), Some(&0));
+}
+
+#[test]
+fn test_transform_index_heading() {
+    // Transposing the cells sends North to West, as the compass action of `FlipAntiDiagonal` does.
+    let transposed = &grid() * Transform::FlipDiagonal;
+    assert_eq!(transposed.neighbour((1, 1), Direction::West), Some(&1));
+    assert_eq!(Direction::North * Transform::FlipAntiDiagonal, Direction::West);
+    assert_eq!(Transform::FlipDiagonal.index_heading(), Transform::FlipAntiDiagonal);
+    assert_eq!(Transform::FlipAntiDiagonal.index_heading(), Transform::FlipDiagonal);
+    for transform in ALL_TRANSFORMS {
+        assert_eq!(transform.index_heading().index_heading(), transform);
+        if !matches!(transform, Transform::FlipDiagonal | Transform::FlipAntiDiagonal) {
+            assert_eq!(transform.index_heading(), transform);
+        }
+    }
+}
+
+#[cfg(feature = "array")]
+#[test]
+fn test_grid_array_interop() {
+    use ndarray::{Array2, arr2};
+
+    let grid = grid();
+    assert_eq!(grid.view(), arr2(&[[0, 1, 2], [10, 11, 12]]));
+    assert_eq!(grid.clone().into_array(), grid.view());
+    assert_eq!(Grid::from(grid.clone().into_array()), grid);
+
+    // Arrays in any memory layout convert in logical order.
+    let transposed: Array2<usize> = grid.view().t().to_owned();
+    assert_eq!(Grid::from(transposed), &grid * Transform::FlipDiagonal);
+
+    for transform in ALL_TRANSFORMS {
+        assert_eq!((&grid * transform).view(), transform * grid.view());
+    }
+
+    let mut grid = grid;
+    grid.view_mut()[[1, 2]] = 99;
+    assert_eq!(grid[(1, 2)], 99);
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "image")]
+
+use image::{DynamicImage, GrayImage, ImageBuffer, Luma, Rgb, RgbImage, metadata::Orientation};
+use nav::{ALL_TRANSFORMS, Transform};
+
+/// A 3x2 greyscale image whose pixels are numbered in row-major order.
+fn gray() -> GrayImage {
+    ImageBuffer::from_fn(3, 2, |x, y| Luma([u8::try_from(y * 3 + x + 1).unwrap()]))
+}
+
+/// A 4x3 colour image with a distinct colour in every pixel.
+fn rgb() -> RgbImage {
+    ImageBuffer::from_fn(4, 3, |x, y| Rgb([u8::try_from(x).unwrap(), u8::try_from(y).unwrap(), 7]))
+}
+
+#[test]
+fn test_image_buffer_transform() {
+    let cases = [
+        (Transform::Identity, vec![1, 2, 3, 4, 5, 6], (3, 2)),
+        (Transform::Rotate90, vec![4, 1, 5, 2, 6, 3], (2, 3)),
+        (Transform::Rotate180, vec![6, 5, 4, 3, 2, 1], (3, 2)),
+        (Transform::Rotate270, vec![3, 6, 2, 5, 1, 4], (2, 3)),
+        (Transform::FlipHorizontal, vec![3, 2, 1, 6, 5, 4], (3, 2)),
+        (Transform::FlipDiagonal, vec![1, 4, 2, 5, 3, 6], (2, 3)),
+        (Transform::FlipVertical, vec![4, 5, 6, 1, 2, 3], (3, 2)),
+        (Transform::FlipAntiDiagonal, vec![6, 3, 5, 2, 4, 1], (2, 3)),
+    ];
+    let image = gray();
+    for (transform, expected, dims) in cases {
+        let transformed = transform * &image;
+        assert_eq!(transformed.dimensions(), dims, "failed: {:?}", transform);
+        assert_eq!(transformed.into_raw(), expected, "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn test_image_buffer_matches_buffer() {
+    let image = rgb();
+    let pixels: Vec<Rgb<u8>> = image.pixels().copied().collect();
+    for transform in ALL_TRANSFORMS {
+        let transformed = transform * &image;
+        let (expected, width, height) = transform.apply_buffer(&pixels, 4, 3);
+        assert_eq!(
+            transformed.dimensions(),
+            (u32::try_from(width).unwrap(), u32::try_from(height).unwrap())
+        );
+        assert_eq!(
+            transformed.pixels().copied().collect::<Vec<_>>(),
+            expected,
+            "failed: {:?}",
+            transform
+        );
+    }
+}
+
+#[test]
+fn test_image_buffer_composition() {
+    let image = rgb();
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            assert_eq!((a * b) * &image, b * &(a * &image), "failed: {:?} * {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_dynamic_image_transform() {
+    let image = DynamicImage::ImageRgb8(rgb());
+    for transform in ALL_TRANSFORMS {
+        let transformed = transform * &image;
+        assert!(matches!(transformed, DynamicImage::ImageRgb8(_)));
+        assert_eq!(transformed.to_rgb8(), transform * &rgb(), "failed: {:?}", transform);
+
+        let mut in_place = image.clone();
+        transform.apply_image(&mut in_place);
+        assert_eq!(in_place, transformed, "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn test_orientation_round_trip() {
+    for transform in ALL_TRANSFORMS {
+        assert_eq!(Transform::from(Orientation::from(transform)), transform);
+    }
+}
+
+#[test]
+fn test_orientation_matches_transform() {
+    let image = DynamicImage::ImageLuma8(gray());
+    for transform in ALL_TRANSFORMS {
+        let mut oriented = image.clone();
+        oriented.apply_orientation(transform.into());
+        assert_eq!(oriented, transform * &image, "failed: {:?}", transform);
+    }
+}
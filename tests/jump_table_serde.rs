@@ -0,0 +1,65 @@
+#![cfg(all(feature = "serde", feature = "alloc"))]
+
+use nav::{Direction, Grid, JumpTable};
+use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+/// The padded passability of the 1x2 map `..`.
+const OPEN: [bool; 12] = [
+    false, false, false, false, false, true, true, false, false, false, false, false,
+];
+
+/// The jump distances of the 1x2 map `..`, for each cell and `Direction`.
+const DISTANCES: [i32; 8] = [0, -1, 0, 0, 0, 0, 0, -1];
+
+/// Returns the serialized tokens of a table with the given fields.
+fn tokens(rows: u64, cols: u64, open: &[bool], distances: &[i32]) -> Vec<Token> {
+    let mut tokens = vec![
+        Token::Struct {
+            name: "JumpTable",
+            len: 4,
+        },
+        Token::Str("rows"),
+        Token::U64(rows),
+        Token::Str("cols"),
+        Token::U64(cols),
+        Token::Str("open"),
+        Token::Seq { len: Some(open.len()) },
+    ];
+    tokens.extend(open.iter().map(|&cell| Token::Bool(cell)));
+    tokens.extend([
+        Token::SeqEnd,
+        Token::Str("distances"),
+        Token::Seq {
+            len: Some(distances.len()),
+        },
+    ]);
+    tokens.extend(distances.iter().map(|&distance| Token::I32(distance)));
+    tokens.extend([Token::SeqEnd, Token::StructEnd]);
+    tokens
+}
+
+#[test]
+fn test_jump_table_serde_round_trip() {
+    let table = Grid::new((1, 2), '.').jump_table::<Direction, _>(|&cell| cell == '.');
+    assert_tokens(&table, &tokens(1, 2, &OPEN, &DISTANCES));
+}
+
+#[test]
+fn test_jump_table_serde_rejects_inconsistent_tables() {
+    let error = "Jump table does not match its dimensions";
+    // Lengths which do not match the dimensions.
+    assert_de_tokens_error::<JumpTable<Direction>>(&tokens(2, 2, &OPEN, &DISTANCES), error);
+    assert_de_tokens_error::<JumpTable<Direction>>(&tokens(1, 2, &OPEN, &DISTANCES[..4]), error);
+
+    // A passable border, which searches would step through.
+    let mut open = OPEN;
+    open[4] = true;
+    assert_de_tokens_error::<JumpTable<Direction>>(&tokens(1, 2, &open, &DISTANCES), error);
+
+    // A jump which travels off the map.
+    let mut distances = DISTANCES;
+    distances[1] = 2;
+    assert_de_tokens_error::<JumpTable<Direction>>(&tokens(1, 2, &OPEN, &distances), error);
+    distances[1] = i32::MIN;
+    assert_de_tokens_error::<JumpTable<Direction>>(&tokens(1, 2, &OPEN, &distances), error);
+}
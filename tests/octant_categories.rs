@@ -0,0 +1,40 @@
+use nav::{ALL_OCTANTS, Octant};
+
+#[test]
+fn test_octant_is_cardinal() {
+    assert!(Octant::North.is_cardinal());
+    assert!(!Octant::NorthEast.is_cardinal());
+    assert!(Octant::East.is_cardinal());
+    assert!(!Octant::SouthEast.is_cardinal());
+    assert!(Octant::South.is_cardinal());
+    assert!(!Octant::SouthWest.is_cardinal());
+    assert!(Octant::West.is_cardinal());
+    assert!(!Octant::NorthWest.is_cardinal());
+}
+
+#[test]
+fn test_octant_is_diagonal() {
+    assert!(!Octant::North.is_diagonal());
+    assert!(Octant::NorthEast.is_diagonal());
+    assert!(!Octant::East.is_diagonal());
+    assert!(Octant::SouthEast.is_diagonal());
+    assert!(!Octant::South.is_diagonal());
+    assert!(Octant::SouthWest.is_diagonal());
+    assert!(!Octant::West.is_diagonal());
+    assert!(Octant::NorthWest.is_diagonal());
+}
+
+#[test]
+fn test_octant_categories_are_exhaustive() {
+    // Each octant must belong to exactly one category
+    for octant in ALL_OCTANTS {
+        let categories = [octant.is_cardinal(), octant.is_diagonal()];
+
+        assert_eq!(
+            categories.iter().filter(|&&x| x).count(),
+            1,
+            "Octant {:?} should belong to exactly one category",
+            octant
+        );
+    }
+}
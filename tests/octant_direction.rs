@@ -0,0 +1,31 @@
+use nav::{ALL_DIRECTIONS, ALL_OCTANTS, Direction, Octant};
+
+#[test]
+fn test_octant_from_direction() {
+    assert_eq!(Octant::from(Direction::North), Octant::North);
+    assert_eq!(Octant::from(Direction::East), Octant::East);
+    assert_eq!(Octant::from(Direction::South), Octant::South);
+    assert_eq!(Octant::from(Direction::West), Octant::West);
+}
+
+#[test]
+fn test_direction_try_from_octant() {
+    for octant in ALL_OCTANTS {
+        let result = Direction::try_from(octant);
+        assert_eq!(result.is_ok(), octant.is_cardinal(), "failed: {:?}", octant);
+    }
+}
+
+#[test]
+fn test_direction_roundtrip() {
+    for direction in ALL_DIRECTIONS {
+        assert_eq!(Direction::try_from(Octant::from(direction)).unwrap(), direction);
+    }
+}
+
+#[test]
+fn test_direction_neg_is_preserved() {
+    for direction in ALL_DIRECTIONS {
+        assert_eq!(Octant::from(-direction), -Octant::from(direction));
+    }
+}
@@ -0,0 +1,20 @@
+use nav::{ALL_OCTANTS, Octant};
+
+#[test]
+fn test_octant_neg() {
+    assert_eq!(-Octant::North, Octant::South);
+    assert_eq!(-Octant::NorthEast, Octant::SouthWest);
+    assert_eq!(-Octant::East, Octant::West);
+    assert_eq!(-Octant::SouthEast, Octant::NorthWest);
+    assert_eq!(-Octant::South, Octant::North);
+    assert_eq!(-Octant::SouthWest, Octant::NorthEast);
+    assert_eq!(-Octant::West, Octant::East);
+    assert_eq!(-Octant::NorthWest, Octant::SouthEast);
+}
+
+#[test]
+fn test_octant_neg_is_involution() {
+    for octant in ALL_OCTANTS {
+        assert_eq!(-(-octant), octant);
+    }
+}
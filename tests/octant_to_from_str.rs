@@ -0,0 +1,54 @@
+use nav::{ALL_OCTANTS, Octant, ParseError};
+use std::str::FromStr;
+
+#[test]
+fn test_octant_to_string() {
+    assert_eq!(Octant::North.to_string(), "N");
+    assert_eq!(Octant::NorthEast.to_string(), "NE");
+    assert_eq!(Octant::East.to_string(), "E");
+    assert_eq!(Octant::SouthEast.to_string(), "SE");
+    assert_eq!(Octant::South.to_string(), "S");
+    assert_eq!(Octant::SouthWest.to_string(), "SW");
+    assert_eq!(Octant::West.to_string(), "W");
+    assert_eq!(Octant::NorthWest.to_string(), "NW");
+}
+
+#[test]
+fn test_octant_from_str() {
+    // Test short forms
+    assert_eq!(Octant::from_str("N").unwrap(), Octant::North);
+    assert_eq!(Octant::from_str("NE").unwrap(), Octant::NorthEast);
+    assert_eq!(Octant::from_str("SW").unwrap(), Octant::SouthWest);
+
+    // Test full forms
+    assert_eq!(Octant::from_str("East").unwrap(), Octant::East);
+    assert_eq!(Octant::from_str("SouthEast").unwrap(), Octant::SouthEast);
+    assert_eq!(Octant::from_str("NorthWest").unwrap(), Octant::NorthWest);
+
+    // Test case insensitivity
+    assert_eq!(Octant::from_str("ne").unwrap(), Octant::NorthEast);
+    assert_eq!(Octant::from_str("SOUTHWEST").unwrap(), Octant::SouthWest);
+    assert_eq!(Octant::from_str("wEsT").unwrap(), Octant::West);
+}
+
+#[test]
+fn test_octant_from_str_error() {
+    assert!(Octant::from_str("").is_err());
+    assert!(Octant::from_str("X").is_err());
+    assert!(Octant::from_str("NS").is_err());
+    assert!(Octant::from_str("North East").is_err());
+    assert!(Octant::from_str("123").is_err());
+
+    let err = Octant::from_str("NNE").unwrap_err();
+    assert_eq!(err, ParseError::InvalidOctant("NNE".into()));
+    assert_eq!(err.input(), "NNE");
+}
+
+#[test]
+fn test_roundtrip_conversion() {
+    for octant in ALL_OCTANTS {
+        let s = octant.to_string();
+        let parsed = Octant::from_str(&s).unwrap();
+        assert_eq!(octant, parsed);
+    }
+}
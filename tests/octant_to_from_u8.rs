@@ -0,0 +1,22 @@
+use nav::{ALL_OCTANTS, Octant};
+
+#[test]
+fn octant_to_u8() {
+    for (i, octant) in ALL_OCTANTS.into_iter().enumerate() {
+        let v: u8 = octant.into();
+        assert_eq!(usize::from(v), i);
+    }
+}
+
+#[test]
+fn octant_from_u8() {
+    assert_eq!(Octant::try_from(0).unwrap(), Octant::North);
+    assert_eq!(Octant::try_from(1).unwrap(), Octant::NorthEast);
+    assert_eq!(Octant::try_from(2).unwrap(), Octant::East);
+    assert_eq!(Octant::try_from(3).unwrap(), Octant::SouthEast);
+    assert_eq!(Octant::try_from(4).unwrap(), Octant::South);
+    assert_eq!(Octant::try_from(5).unwrap(), Octant::SouthWest);
+    assert_eq!(Octant::try_from(6).unwrap(), Octant::West);
+    assert_eq!(Octant::try_from(7).unwrap(), Octant::NorthWest);
+    assert!(Octant::try_from(8).is_err());
+}
@@ -0,0 +1,79 @@
+use nav::{ALL_DIRECTIONS, ALL_OCTANTS, ALL_TRANSFORMS, Octant, Transform};
+
+const DIAGONAL_CASES: &[(Octant, Transform, Octant)] = &[
+    // identity
+    (Octant::NorthEast, Transform::Identity, Octant::NorthEast),
+    (Octant::SouthEast, Transform::Identity, Octant::SouthEast),
+    (Octant::SouthWest, Transform::Identity, Octant::SouthWest),
+    (Octant::NorthWest, Transform::Identity, Octant::NorthWest),
+    // rotate
+    (Octant::NorthEast, Transform::Rotate90, Octant::SouthEast),
+    (Octant::SouthEast, Transform::Rotate90, Octant::SouthWest),
+    (Octant::SouthWest, Transform::Rotate90, Octant::NorthWest),
+    (Octant::NorthWest, Transform::Rotate90, Octant::NorthEast),
+    (Octant::NorthEast, Transform::Rotate180, Octant::SouthWest),
+    (Octant::SouthEast, Transform::Rotate180, Octant::NorthWest),
+    (Octant::SouthWest, Transform::Rotate180, Octant::NorthEast),
+    (Octant::NorthWest, Transform::Rotate180, Octant::SouthEast),
+    (Octant::NorthEast, Transform::Rotate270, Octant::NorthWest),
+    (Octant::SouthEast, Transform::Rotate270, Octant::NorthEast),
+    (Octant::SouthWest, Transform::Rotate270, Octant::SouthEast),
+    (Octant::NorthWest, Transform::Rotate270, Octant::SouthWest),
+    // flips
+    (Octant::NorthEast, Transform::FlipHorizontal, Octant::NorthWest),
+    (Octant::SouthEast, Transform::FlipHorizontal, Octant::SouthWest),
+    (Octant::SouthWest, Transform::FlipHorizontal, Octant::SouthEast),
+    (Octant::NorthWest, Transform::FlipHorizontal, Octant::NorthEast),
+    (Octant::NorthEast, Transform::FlipVertical, Octant::SouthEast),
+    (Octant::SouthEast, Transform::FlipVertical, Octant::NorthEast),
+    (Octant::SouthWest, Transform::FlipVertical, Octant::NorthWest),
+    (Octant::NorthWest, Transform::FlipVertical, Octant::SouthWest),
+    (Octant::NorthEast, Transform::FlipDiagonal, Octant::NorthEast),
+    (Octant::SouthEast, Transform::FlipDiagonal, Octant::NorthWest),
+    (Octant::SouthWest, Transform::FlipDiagonal, Octant::SouthWest),
+    (Octant::NorthWest, Transform::FlipDiagonal, Octant::SouthEast),
+    (Octant::NorthEast, Transform::FlipAntiDiagonal, Octant::SouthWest),
+    (Octant::SouthEast, Transform::FlipAntiDiagonal, Octant::SouthEast),
+    (Octant::SouthWest, Transform::FlipAntiDiagonal, Octant::NorthEast),
+    (Octant::NorthWest, Transform::FlipAntiDiagonal, Octant::NorthWest),
+];
+
+#[test]
+fn diagonal_transforms_mul() {
+    for &(orig, transform, expected) in DIAGONAL_CASES {
+        assert_eq!(orig * transform, expected, "failed: {:?} * {:?}", orig, transform);
+    }
+}
+
+#[test]
+fn diagonal_transforms_mul_assign() {
+    for &(orig, transform, expected) in DIAGONAL_CASES {
+        let mut tmp = orig;
+        tmp *= transform;
+        assert_eq!(tmp, expected, "failed: {:?} *= {:?}", orig, transform);
+    }
+}
+
+#[test]
+fn cardinal_transforms_match_direction() {
+    for direction in ALL_DIRECTIONS {
+        for transform in ALL_TRANSFORMS {
+            assert_eq!(
+                Octant::from(direction) * transform,
+                Octant::from(direction * transform),
+                "failed: {:?} * {:?}",
+                direction,
+                transform
+            );
+        }
+    }
+}
+
+#[test]
+fn transforms_preserve_opposites() {
+    for octant in ALL_OCTANTS {
+        for transform in ALL_TRANSFORMS {
+            assert_eq!(-octant * transform, -(octant * transform));
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use nav::{Direction, Octant, ParseError, Transform};
+use std::error::Error;
+
+#[test]
+fn test_parse_error_is_error() {
+    fn parse_all(direction: &str, transform: &str) -> Result<(Direction, Transform), Box<dyn Error>> {
+        Ok((direction.parse()?, transform.parse()?))
+    }
+
+    assert_eq!(parse_all("N", "R").unwrap(), (Direction::North, Transform::Rotate90));
+
+    let err = parse_all("N", "Q").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ParseError>(),
+        Some(&ParseError::InvalidTransform("Q".into()))
+    );
+}
+
+#[test]
+fn test_parse_error_preserves_input_case() {
+    let err = "NorthNorthEast".parse::<Octant>().unwrap_err();
+    assert_eq!(err.input(), "NorthNorthEast");
+    assert!(err.to_string().contains("\"NorthNorthEast\""));
+}
+
+#[test]
+fn test_parse_error_display() {
+    assert_eq!(
+        ParseError::InvalidDirection("".into()).to_string(),
+        "Invalid direction \"\", expected one of: N, E, S, W, North, East, South, West"
+    );
+    assert_eq!(
+        ParseError::InvalidOctant("X".into()).to_string(),
+        "Invalid octant \"X\", expected one of: N, NE, E, SE, S, SW, W, NW, North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest"
+    );
+}
+
+#[test]
+fn test_parse_error_truncates_long_input() {
+    let input = "é".repeat(40);
+    let err = input.parse::<Direction>().unwrap_err();
+    assert!(err.input().len() <= nav::MAX_REJECTED_INPUT_LEN);
+    assert!(input.starts_with(err.input()));
+
+    let ParseError::InvalidDirection(rejected) = err else {
+        panic!("unexpected error: {:?}", err);
+    };
+    assert!(rejected.is_truncated());
+    assert!(err.to_string().contains("...,"));
+}
+
+#[test]
+fn test_parse_error_short_input_is_not_truncated() {
+    let ParseError::InvalidTransform(rejected) = "Q".parse::<Transform>().unwrap_err() else {
+        panic!("unexpected error");
+    };
+    assert!(!rejected.is_truncated());
+    assert_eq!(rejected, "Q");
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{cost, map, path_cost, random_map};
+use core::f64::consts::SQRT_2;
+use nav::{Direction, Heuristic, Move, Octant};
+
+#[test]
+fn test_heuristic_estimate() {
+    let (a, b) = ((1, 2), (4, 8));
+    assert_eq!(Heuristic::Zero.estimate(a, b), 0.0);
+    assert_eq!(Heuristic::Manhattan.estimate(a, b), 9.0);
+    assert_eq!(Heuristic::Chebyshev.estimate(a, b), 6.0);
+    assert!((Heuristic::Octile.estimate(a, b) - (3.0 + 3.0 * SQRT_2)).abs() < 1e-12);
+    assert_eq!(Heuristic::Octile.estimate(b, a), Heuristic::Octile.estimate(a, b));
+}
+
+#[test]
+fn test_move_length() {
+    assert_eq!(Direction::North.length(), 1.0);
+    assert_eq!(Octant::East.length(), 1.0);
+    assert_eq!(Octant::SouthWest.length(), SQRT_2);
+    assert_eq!(Direction::ALL.len(), 4);
+    assert_eq!(Octant::ALL.len(), 8);
+}
+
+#[test]
+fn test_astar_cardinal() {
+    let grid = map(&["....", ".##.", "...."]);
+    let (path, total) = grid
+        .astar::<Direction, _>((0, 0), (2, 3), Heuristic::Manhattan, cost)
+        .unwrap();
+    assert_eq!(total, 5.0);
+    assert_eq!(path.len(), 5);
+    assert_eq!(path_cost(&grid, (0, 0), &path, cost), total);
+
+    assert_eq!(
+        grid.astar::<Direction, _>((0, 0), (0, 0), Heuristic::Manhattan, cost),
+        Some((vec![], 0.0))
+    );
+    assert_eq!(grid.astar::<Direction, _>((0, 0), (1, 1), Heuristic::Manhattan, cost), None);
+    assert_eq!(grid.astar::<Direction, _>((0, 0), (3, 0), Heuristic::Manhattan, cost), None);
+}
+
+#[test]
+fn test_astar_weighted() {
+    // Going through the swamp is shorter but more expensive than going around it.
+    let grid = map(&["...", "999", "...", "..."]);
+    let (path, total) = grid
+        .astar::<Direction, _>((0, 1), (2, 1), Heuristic::Manhattan, |_| Some(1.0))
+        .unwrap();
+    assert_eq!((path, total), (vec![Direction::South, Direction::South], 2.0));
+
+    let (_, total) = grid
+        .astar::<Direction, _>((0, 1), (2, 1), Heuristic::Manhattan, cost)
+        .unwrap();
+    assert_eq!(total, 10.0);
+}
+
+#[test]
+fn test_astar_octant() {
+    let grid = map(&["....", "....", "...."]);
+    let (path, total) = grid.astar::<Octant, _>((0, 0), (2, 3), Heuristic::Octile, cost).unwrap();
+    assert_eq!(path.len(), 3);
+    assert!((total - (1.0 + 2.0 * SQRT_2)).abs() < 1e-12);
+    assert_eq!(path.iter().filter(|step| step.is_diagonal()).count(), 2);
+}
+
+#[test]
+fn test_astar_no_corner_cutting() {
+    let grid = map(&[".#", ".."]);
+    let (path, _) = grid.astar::<Octant, _>((0, 0), (1, 1), Heuristic::Octile, cost).unwrap();
+    assert_eq!(path, [Octant::South, Octant::East]);
+
+    // Squeezing diagonally between two walls is forbidden.
+    let grid = map(&[".#", "#."]);
+    assert_eq!(grid.astar::<Octant, _>((0, 0), (1, 1), Heuristic::Octile, cost), None);
+}
+
+#[test]
+fn test_astar_matches_dijkstra() {
+    for seed in 0..20 {
+        let grid = random_map(seed, (12, 15), 2);
+        let goal = (11, 14);
+        for (heuristic, octant) in [
+            (Heuristic::Manhattan, false),
+            (Heuristic::Chebyshev, false),
+            (Heuristic::Octile, true),
+            (Heuristic::Chebyshev, true),
+        ] {
+            let mut grid = grid.clone();
+            grid[(0, 0)] = '.';
+            grid[goal] = '.';
+            if octant {
+                let expected = grid.dijkstra::<Octant, _>((0, 0), goal, cost);
+                let found = grid.astar::<Octant, _>((0, 0), goal, heuristic, cost);
+                assert_eq!(found.is_some(), expected.is_some());
+                if let (Some((path, total)), Some((_, expected))) = (found, expected) {
+                    assert!((total - expected).abs() < 1e-9, "seed {seed}: {total} != {expected}");
+                    assert!((path_cost(&grid, (0, 0), &path, cost) - total).abs() < 1e-9);
+                }
+            } else {
+                let expected = grid.dijkstra::<Direction, _>((0, 0), goal, cost);
+                let found = grid.astar::<Direction, _>((0, 0), goal, heuristic, cost);
+                assert_eq!(found.is_some(), expected.is_some());
+                if let (Some((path, total)), Some((_, expected))) = (found, expected) {
+                    assert_eq!(total, expected, "seed {seed}");
+                    assert_eq!(path_cost(&grid, (0, 0), &path, cost), total);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dijkstra_matches_bfs() {
+    for seed in 0..10 {
+        let grid = random_map(seed, (10, 10), 2);
+        let field = grid.bfs([(0, 0)], |cell| cost(cell).is_some());
+        for (target, _) in grid.indexed_iter() {
+            let found = grid.dijkstra::<Direction, _>((0, 0), target, |cell| cost(cell).map(|_| 1.0));
+            assert_eq!(
+                found.map(|(path, _)| path.len()),
+                field.distance(target),
+                "seed {seed}, target {target:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{floor, map, path_cost, random_map, unit};
+use core::f64::consts::SQRT_2;
+use nav::{Direction, Grid, Heuristic, Move, Octant};
+
+/// Checks that A*, JPS and JPS+ agree on reachability and path length between every pair of sampled positions.
+fn check_matches_astar<M: Move>(grid: &Grid<char>, heuristic: Heuristic, targets: &[(usize, usize)]) {
+    let table = grid.jump_table::<M, _>(floor);
+    for &start in targets {
+        for &goal in targets {
+            let expected = grid.astar::<M, _>(start, goal, heuristic, unit);
+            for found in [grid.jps::<M, _>(start, goal, floor), table.path(start, goal)] {
+                assert_eq!(found.is_some(), expected.is_some(), "{start:?} -> {goal:?}");
+                if let (Some((path, total)), Some((_, expected))) = (found, &expected) {
+                    assert!(
+                        (total - expected).abs() < 1e-9,
+                        "{start:?} -> {goal:?}: {total} != {expected}"
+                    );
+                    assert!((path_cost(grid, start, &path, unit) - total).abs() < 1e-9);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_jps_open() {
+    let grid = map(&[".....", ".....", "....."]);
+    let (path, total) = grid.jps::<Direction, _>((0, 0), (2, 4), floor).unwrap();
+    assert_eq!(path.len(), 6);
+    assert_eq!(total, 6.0);
+
+    let (path, total) = grid.jps::<Octant, _>((0, 0), (2, 4), floor).unwrap();
+    assert_eq!(path.len(), 4);
+    assert!((total - (2.0 + 2.0 * SQRT_2)).abs() < 1e-12);
+
+    assert_eq!(grid.jps::<Octant, _>((1, 1), (1, 1), floor), Some((vec![], 0.0)));
+    assert_eq!(grid.jps::<Octant, _>((0, 0), (3, 0), floor), None);
+}
+
+#[test]
+fn test_jps_walls() {
+    let grid = map(&["..#..", "..#..", "....."]);
+    let (path, total) = grid.jps::<Direction, _>((0, 0), (0, 4), floor).unwrap();
+    assert_eq!(total, 8.0);
+    assert_eq!(path_cost(&grid, (0, 0), &path, unit), total);
+
+    // Squeezing diagonally between two walls is forbidden.
+    let grid = map(&[".#", "#."]);
+    assert_eq!(grid.jps::<Octant, _>((0, 0), (1, 1), floor), None);
+    assert_eq!(grid.jump_table::<Octant, _>(floor).path((0, 0), (1, 1)), None);
+    assert_eq!(grid.jps::<Octant, _>((0, 0), (1, 0), floor), None);
+}
+
+#[test]
+fn test_jump_table() {
+    let grid = map(&["..#.#", ".....", "#...."]);
+    let table = grid.jump_table::<Direction, _>(floor);
+    assert_eq!(table.dim(), (3, 5));
+    assert!(table.is_passable((0, 0)));
+    assert!(!table.is_passable((0, 4)));
+    assert!(!table.is_passable((3, 0)));
+
+    // Travelling east along the middle row, the walls at (2, 0) and (0, 2) each reveal a forced neighbour.
+    assert_eq!(table.jump((1, 0), Direction::East), Some(((1, 1), 1)));
+    assert_eq!(table.jump((1, 1), Direction::East), Some(((1, 3), 2)));
+    assert_eq!(table.jump((1, 3), Direction::East), None);
+    assert_eq!(table.jump((0, 0), Direction::East), None);
+    assert_eq!(table.jump((0, 0), Direction::North), None);
+    assert_eq!(table.jump((5, 0), Direction::East), None);
+
+    let (path, total) = table.path((0, 0), (2, 4)).unwrap();
+    assert_eq!(total, 6.0);
+    assert_eq!(path_cost(&grid, (0, 0), &path, unit), total);
+    assert_eq!(table.path((0, 0), (0, 4)), None);
+}
+
+#[test]
+fn test_jps_matches_astar_cardinal() {
+    for seed in 0..12 {
+        let grid = random_map(seed, (14, 17), seed % 4);
+        let targets: Vec<_> = grid.indexed_iter().map(|(pos, _)| pos).step_by(11).collect();
+        check_matches_astar::<Direction>(&grid, Heuristic::Manhattan, &targets);
+    }
+}
+
+#[test]
+fn test_jps_matches_astar_octant() {
+    for seed in 0..12 {
+        let grid = random_map(seed, (14, 17), seed % 4);
+        let targets: Vec<_> = grid.indexed_iter().map(|(pos, _)| pos).step_by(11).collect();
+        check_matches_astar::<Octant>(&grid, Heuristic::Octile, &targets);
+    }
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{cost, map};
+use core::f64::consts::SQRT_2;
+use nav::{Direction, Grid, Heuristic, Octant, Turn};
+
+/// An open map where every cell costs 1 to enter.
+fn open(dim: (usize, usize)) -> Grid<()> {
+    Grid::new(dim, ())
+}
+
+/// Returns the number of changes of heading along a path.
+fn turns<M: PartialEq>(path: &[M]) -> usize {
+    path.windows(2).filter(|pair| pair[0] != pair[1]).count()
+}
+
+#[test]
+fn test_astar_turn_penalty_straightens_staircase() {
+    // A staircase of cheap cells is the unique cheapest path, but turns five times.
+    let grid = map(&["1122", "2112", "2211", "2221"]);
+    let (path, total) = grid
+        .astar_with_turns::<Direction, _, _>((0, 0), (3, 3), Heuristic::Manhattan, cost, |_, _| 0.0)
+        .unwrap();
+    use Direction::{East, South};
+    assert_eq!(path, [East, South, East, South, East, South]);
+    assert_eq!(total, 6.0);
+    assert_eq!(turns(&path), 5);
+
+    let (path, total) = grid
+        .astar_with_turns::<Direction, _, _>(
+            (0, 0),
+            (3, 3),
+            Heuristic::Manhattan,
+            cost,
+            |a, b| {
+                if a == b { 0.0 } else { 10.0 }
+            },
+        )
+        .unwrap();
+    assert_eq!(path, [East, East, East, South, South, South]);
+    assert_eq!(total, 19.0);
+}
+
+#[test]
+fn test_astar_turn_penalty_minimises_turns() {
+    let grid = open((5, 5));
+    let (path, total) = grid
+        .astar_with_turns::<Direction, _, _>(
+            (0, 0),
+            (4, 4),
+            Heuristic::Manhattan,
+            |_| Some(1.0),
+            |a, b| {
+                if a == b { 0.0 } else { 10.0 }
+            },
+        )
+        .unwrap();
+    assert_eq!(path.len(), 8);
+    assert_eq!(turns(&path), 1);
+    assert_eq!(total, 18.0);
+}
+
+#[test]
+fn test_astar_turn_penalty_from_turns() {
+    // Reversing is forbidden in practice, and left turns cost more than right turns.
+    let penalty = |previous: Direction, next: Direction| match next - previous {
+        Turn::Straight => 0.0,
+        Turn::Right => 1.0,
+        Turn::Left => 3.0,
+        Turn::Back => 100.0,
+    };
+    let grid = open((3, 3));
+    let (path, total) = grid
+        .astar_with_turns((0, 0), (2, 2), Heuristic::Manhattan, |_| Some(1.0), penalty)
+        .unwrap();
+    assert_eq!(total, 5.0);
+    assert_eq!(path, [Direction::East, Direction::East, Direction::South, Direction::South]);
+}
+
+#[test]
+fn test_astar_turn_penalty_detour() {
+    // Weaving around the swamp is cheapest, but turns three times; ploughing straight through it turns never.
+    let grid = map(&["11911", "55155", "55555"]);
+    let (path, total) = grid
+        .astar_with_turns::<Octant, _, _>((0, 0), (0, 4), Heuristic::Octile, cost, |_, _| 0.0)
+        .unwrap();
+    assert_eq!(path, [Octant::East, Octant::SouthEast, Octant::NorthEast, Octant::East]);
+    assert!((total - (2.0 + 2.0 * SQRT_2)).abs() < 1e-12);
+
+    let (path, total) = grid
+        .astar_with_turns::<Octant, _, _>((0, 0), (0, 4), Heuristic::Octile, cost, |a, b| if a == b { 0.0 } else { 5.0 })
+        .unwrap();
+    assert_eq!(path, [Octant::East; 4]);
+    assert_eq!(total, 12.0);
+}
+
+#[test]
+fn test_astar_with_turns_unreachable() {
+    let grid = open((2, 2));
+    assert_eq!(
+        grid.astar_with_turns::<Direction, _, _>((0, 0), (1, 1), Heuristic::Manhattan, |_| None, |_, _| 1.0),
+        None
+    );
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::map;
+use nav::Direction;
+
+#[test]
+fn test_bfs_distances() {
+    let grid = map(&["...", ".#.", "..."]);
+    let field = grid.bfs([(0, 0)], |&cell| cell == '.');
+    assert_eq!(field.distance((0, 0)), Some(0));
+    assert_eq!(field.distance((0, 2)), Some(2));
+    assert_eq!(field.distance((2, 2)), Some(4));
+    assert_eq!(field.distance((1, 1)), None);
+    assert_eq!(field.distance((5, 5)), None);
+    assert_eq!(
+        field.distances().as_slice(),
+        [Some(0), Some(1), Some(2), Some(1), None, Some(3), Some(2), Some(3), Some(4)]
+    );
+}
+
+#[test]
+fn test_bfs_parents_and_paths() {
+    let grid = map(&["...", "##.", "..."]);
+    let field = grid.bfs([(0, 0)], |&cell| cell == '.');
+    assert_eq!(field.parent((0, 0)), None);
+    assert_eq!(field.parent((0, 1)), Some(Direction::East));
+    assert_eq!(field.parent((1, 2)), Some(Direction::South));
+    assert_eq!(field.path_to((0, 0)), Some(vec![]));
+    assert_eq!(
+        field.path_to((2, 0)),
+        Some(vec![
+            Direction::East,
+            Direction::East,
+            Direction::South,
+            Direction::South,
+            Direction::West,
+            Direction::West
+        ])
+    );
+    assert_eq!(field.path_to((1, 0)), None);
+}
+
+#[test]
+fn test_bfs_paths_are_shortest() {
+    let grid = map(&["....#....", ".##.#.##.", ".#..#..#.", ".#.###.#.", "........."]);
+    let start = (0, 0);
+    let field = grid.bfs([start], |&cell| cell == '.');
+    for (target, _) in grid.indexed_iter() {
+        let Some(path) = field.path_to(target) else {
+            assert_eq!(field.distance(target), None);
+            continue;
+        };
+        assert_eq!(Some(path.len()), field.distance(target));
+        let mut pos = start;
+        for direction in path {
+            pos = grid.step(pos, direction).unwrap();
+            assert_eq!(grid[pos], '.');
+        }
+        assert_eq!(pos, target);
+    }
+}
+
+#[test]
+fn test_bfs_multiple_sources() {
+    let grid = map(&["....."]);
+    let field = grid.bfs([(0, 0), (0, 4), (9, 9)], |_| true);
+    assert_eq!(field.distances().as_slice(), [Some(0), Some(1), Some(2), Some(1), Some(0)]);
+    assert_eq!(field.path_to((0, 3)), Some(vec![Direction::West]));
+}
+
+#[test]
+fn test_bfs_impassable_source() {
+    let grid = map(&["#.."]);
+    let field = grid.bfs([(0, 0)], |&cell| cell == '.');
+    assert_eq!(field.distance((0, 2)), Some(2));
+}
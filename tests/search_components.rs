@@ -0,0 +1,80 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::map;
+use nav::{Connectivity, Octant};
+
+#[test]
+fn test_connectivity_octants() {
+    assert_eq!(
+        Connectivity::Four.octants(),
+        [Octant::North, Octant::East, Octant::South, Octant::West]
+    );
+    assert_eq!(Connectivity::Eight.octants().len(), 8);
+    assert_eq!(Connectivity::default(), Connectivity::Four);
+}
+
+#[test]
+fn test_flood() {
+    let grid = map(&["#..", ".#.", "..#"]);
+    let region = grid.flood((0, 1), Connectivity::Four, |&cell| cell == '.');
+    assert_eq!(region, [(0, 1), (0, 2), (1, 2)]);
+
+    let region = grid.flood((0, 1), Connectivity::Eight, |&cell| cell == '.');
+    assert_eq!(region.len(), 6);
+    assert_eq!(region[0], (0, 1));
+
+    assert!(grid.flood((0, 0), Connectivity::Four, |&cell| cell == '.').is_empty());
+    assert!(grid.flood((3, 3), Connectivity::Four, |_| true).is_empty());
+}
+
+#[test]
+fn test_flood_fill() {
+    let mut grid = map(&["..#..", "..#..", "#####", "....."]);
+    let filled = grid.flood_fill((0, 0), Connectivity::Four, |&cell| cell == '.', 'o');
+    assert_eq!(filled, 4);
+    assert_eq!(grid.row(0), ['o', 'o', '#', '.', '.']);
+    assert_eq!(grid.row(3), ['.'; 5]);
+
+    // Filling with a value which still satisfies the predicate terminates.
+    let filled = grid.flood_fill((3, 0), Connectivity::Eight, |&cell| cell != '#', '.');
+    assert_eq!(filled, 5);
+}
+
+#[test]
+fn test_label_components() {
+    let grid = map(&["#.#", ".#.", "#.#"]);
+    let (labels, count) = grid.label_components(Connectivity::Four, |&cell| cell == '#');
+    assert_eq!(count, 5);
+    assert_eq!(
+        labels.as_slice(),
+        [Some(0), None, Some(1), None, Some(2), None, Some(3), None, Some(4)]
+    );
+
+    let (labels, count) = grid.label_components(Connectivity::Eight, |&cell| cell == '#');
+    assert_eq!(count, 1);
+    assert!(labels.iter().all(|label| label.is_none_or(|label| label == 0)));
+
+    let (_, count) = grid.label_components(Connectivity::Eight, |&cell| cell == '.');
+    assert_eq!(count, 1);
+    let (_, count) = grid.label_components(Connectivity::Four, |&cell| cell == '.');
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn test_label_components_matches_flood() {
+    let grid = map(&["..#..#", ".##...", "#..#.#", "##..##"]);
+    for connectivity in [Connectivity::Four, Connectivity::Eight] {
+        let (labels, count) = grid.label_components(connectivity, |&cell| cell == '.');
+        for (pos, label) in labels.indexed_iter() {
+            let Some(label) = *label else {
+                continue;
+            };
+            assert!(label < count);
+            for cell in grid.flood(pos, connectivity, |&cell| cell == '.') {
+                assert_eq!(labels[cell], Some(label));
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "array")]
+
+use nav::{ALL_TRANSFORMS, Transform};
+use ndarray::{Array3, Array4, Axis, s};
+
+#[test]
+fn test_apply_axes_rotates_each_slice() {
+    let arr = Array3::from_shape_fn((3, 2, 4), |(z, y, x)| z * 100 + y * 10 + x);
+    for transform in ALL_TRANSFORMS {
+        let out = transform.apply_axes(&arr, Axis(1), Axis(2));
+        let (rows, cols) = transform.apply_dims((2, 4));
+        assert_eq!(out.dim(), (3, rows, cols));
+        for z in 0..3 {
+            assert_eq!(
+                out.index_axis(Axis(0), z),
+                transform * arr.index_axis(Axis(0), z),
+                "failed: {:?} on slice {}",
+                transform,
+                z
+            );
+        }
+    }
+}
+
+#[test]
+fn test_apply_axes_on_outer_plane() {
+    let arr = Array3::from_shape_fn((3, 2, 4), |(z, y, x)| z * 100 + y * 10 + x);
+    for transform in ALL_TRANSFORMS {
+        // Transform the (z, x) plane of every y-slice.
+        let out = transform.apply_axes(&arr, Axis(0), Axis(2));
+        for y in 0..2 {
+            assert_eq!(out.index_axis(Axis(1), y), transform * arr.index_axis(Axis(1), y));
+        }
+    }
+}
+
+#[test]
+fn test_apply_axes_reversed_axes() {
+    // Swapping the roles of the axes transposes the plane before and after the transform.
+    let arr = Array3::from_shape_fn((2, 3, 4), |(z, y, x)| z * 100 + y * 10 + x);
+    for transform in ALL_TRANSFORMS {
+        let conjugate = Transform::FlipDiagonal.conjugate(transform);
+        assert_eq!(
+            transform.apply_axes(&arr, Axis(2), Axis(1)),
+            conjugate.apply_axes(&arr, Axis(1), Axis(2)),
+            "failed: {:?}",
+            transform
+        );
+    }
+}
+
+#[test]
+fn test_apply_axes_matches_2d() {
+    let arr = Array3::from_shape_fn((1, 3, 5), |(_, y, x)| y * 5 + x);
+    let plane = arr.index_axis(Axis(0), 0);
+    for transform in ALL_TRANSFORMS {
+        assert_eq!(transform.apply_axes(&plane, Axis(0), Axis(1)), transform * plane);
+    }
+}
+
+#[test]
+fn test_view_axes_matches_apply_axes() {
+    let arr = Array4::from_shape_fn((2, 3, 2, 4), |(a, b, c, d)| a * 1000 + b * 100 + c * 10 + d);
+    for transform in ALL_TRANSFORMS {
+        let view = transform.view_axes(&arr, Axis(1), Axis(3));
+        assert_eq!(view, transform.apply_axes(&arr, Axis(1), Axis(3)));
+    }
+}
+
+#[test]
+fn test_view_axes_mut_writes_through() {
+    let mut arr = Array3::<i32>::zeros((2, 3, 3));
+    {
+        let mut view = Transform::Rotate90.view_axes_mut(&mut arr, Axis(1), Axis(2));
+        view.slice_mut(s![.., 0, 0]).fill(1);
+    }
+    // The top-left of the rotated plane is the bottom-left of the original.
+    assert_eq!(arr[[0, 2, 0]], 1);
+    assert_eq!(arr[[1, 2, 0]], 1);
+    assert_eq!(arr.sum(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Transform axes must be distinct")]
+fn test_apply_axes_same_axis() {
+    let arr = Array3::<u8>::zeros((2, 2, 2));
+    let _ = Transform::Rotate90.apply_axes(&arr, Axis(1), Axis(1));
+}
+
+#[test]
+#[should_panic(expected = "Transform axes out of bounds")]
+fn test_apply_axes_out_of_bounds() {
+    let arr = Array3::<u8>::zeros((2, 2, 2));
+    let _ = Transform::Rotate90.apply_axes(&arr, Axis(1), Axis(3));
+}
@@ -0,0 +1,140 @@
+use nav::{ALL_TRANSFORMS, Transform};
+
+/// A 2x3 grid stored row-major, as `width = 3`, `height = 2`.
+const BUFFER: [u8; 6] = [1, 2, 3, 4, 5, 6];
+
+#[test]
+fn test_apply_buffer_into() {
+    let cases = [
+        (Transform::Identity, [1, 2, 3, 4, 5, 6], (3, 2)),
+        (Transform::Rotate90, [4, 1, 5, 2, 6, 3], (2, 3)),
+        (Transform::Rotate180, [6, 5, 4, 3, 2, 1], (3, 2)),
+        (Transform::Rotate270, [3, 6, 2, 5, 1, 4], (2, 3)),
+        (Transform::FlipHorizontal, [3, 2, 1, 6, 5, 4], (3, 2)),
+        (Transform::FlipDiagonal, [1, 4, 2, 5, 3, 6], (2, 3)),
+        (Transform::FlipVertical, [4, 5, 6, 1, 2, 3], (3, 2)),
+        (Transform::FlipAntiDiagonal, [6, 3, 5, 2, 4, 1], (2, 3)),
+    ];
+    for (transform, expected, dims) in cases {
+        let mut dst = [0; 6];
+        assert_eq!(transform.apply_buffer_into(&BUFFER, 3, 2, &mut dst), dims);
+        assert_eq!(dst, expected, "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn test_apply_buffer_into_matches_apply_index() {
+    let (width, height) = (5, 3);
+    let src: Vec<usize> = (0..width * height).collect();
+    for transform in ALL_TRANSFORMS {
+        let mut dst = vec![0; src.len()];
+        let (new_width, _) = transform.apply_buffer_into(&src, width, height, &mut dst);
+        for row in 0..height {
+            for col in 0..width {
+                let (new_row, new_col) = transform.apply_index((height, width), (row, col));
+                assert_eq!(dst[new_row * new_width + new_col], src[row * width + col]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_apply_buffer_into_empty() {
+    let mut dst: [u8; 0] = [];
+    assert_eq!(Transform::Rotate90.apply_buffer_into(&[], 0, 4, &mut dst), (4, 0));
+}
+
+#[test]
+#[should_panic(expected = "Buffer length does not match dimensions")]
+fn test_apply_buffer_into_wrong_dimensions() {
+    let mut dst = [0; 6];
+    let _ = Transform::Rotate90.apply_buffer_into(&BUFFER, 2, 2, &mut dst);
+}
+
+#[test]
+#[should_panic(expected = "Destination length does not match source")]
+fn test_apply_buffer_into_wrong_destination() {
+    let mut dst = [0; 5];
+    let _ = Transform::Rotate90.apply_buffer_into(&BUFFER, 3, 2, &mut dst);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_apply_buffer() {
+    for transform in ALL_TRANSFORMS {
+        let mut expected = [0; 6];
+        let (width, height) = transform.apply_buffer_into(&BUFFER, 3, 2, &mut expected);
+        assert_eq!(transform.apply_buffer(&BUFFER, 3, 2), (expected.to_vec(), width, height));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_apply_buffer_composition() {
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            let (once, ..) = (a * b).apply_buffer(&BUFFER, 3, 2);
+            let (first, width, height) = a.apply_buffer(&BUFFER, 3, 2);
+            let (twice, ..) = b.apply_buffer(&first, width, height);
+            assert_eq!(once, twice, "failed: {:?} * {:?}", a, b);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_apply_nested() {
+    let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    assert_eq!(
+        Transform::Rotate90.apply_nested(&grid),
+        vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+    );
+    for transform in ALL_TRANSFORMS {
+        let (buffer, width, _) = transform.apply_buffer(&BUFFER, 3, 2);
+        let expected: Vec<Vec<u8>> = buffer.chunks(width).map(<[u8]>::to_vec).collect();
+        assert_eq!(transform.apply_nested(&grid), expected, "failed: {:?}", transform);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_apply_nested_accepts_slices() {
+    let grid: [&[char]; 2] = [&['a', 'b'], &['c', 'd']];
+    assert_eq!(
+        Transform::FlipDiagonal.apply_nested(&grid),
+        vec![vec!['a', 'c'], vec!['b', 'd']]
+    );
+    assert!(Transform::Rotate90.apply_nested::<u8, Vec<u8>>(&[]).is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "Rows must all have the same length")]
+fn test_apply_nested_ragged() {
+    let _ = Transform::Rotate90.apply_nested(&[vec![1, 2], vec![3]]);
+}
+
+#[cfg(all(feature = "array", feature = "alloc"))]
+#[test]
+fn test_apply_buffer_matches_array() {
+    use ndarray::Array2;
+
+    let (width, height) = (4, 3);
+    let src: Vec<u32> = (0..12).collect();
+    let arr = Array2::from_shape_vec((height, width), src.clone()).unwrap();
+    for transform in ALL_TRANSFORMS {
+        let (buffer, new_width, new_height) = transform.apply_buffer(&src, width, height);
+        let expected = transform * &arr;
+        assert_eq!(expected.dim(), (new_height, new_width));
+        assert_eq!(
+            buffer,
+            expected.iter().copied().collect::<Vec<_>>(),
+            "failed: {:?}",
+            transform
+        );
+
+        let nested: Vec<Vec<u32>> = arr.rows().into_iter().map(|row| row.to_vec()).collect();
+        let expected_nested: Vec<Vec<u32>> = expected.rows().into_iter().map(|row| row.to_vec()).collect();
+        assert_eq!(transform.apply_nested(&nested), expected_nested);
+    }
+}
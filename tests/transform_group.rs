@@ -0,0 +1,138 @@
+use nav::{ALL_TRANSFORMS, Transform};
+use std::str::FromStr;
+
+#[rustfmt::skip]
+const CAYLEY: [[&str; 8]; 8] = [
+    // I   R    U    L    |    /    -    \
+    ["I", "R", "U", "L", "|", "/", "-", "\\"],  // I
+    ["R", "U", "L", "I", "/", "-", "\\","|"],   // R
+    ["U", "L", "I", "R", "-", "\\","|", "/"],   // U
+    ["L", "I", "R", "U", "\\","|", "/", "-"],   // L
+    ["|", "\\","-", "/", "I", "L", "U", "R"],   // |
+    ["/", "|", "\\","-", "R", "I", "L", "U"],   // /
+    ["-", "/", "|", "\\","U", "R", "I", "L"],   // -
+    ["\\","-", "/", "|", "L", "U", "R", "I"],   // \
+];
+
+/// Multiplies two transforms by looking up the Cayley table.
+fn table(a: Transform, b: Transform) -> Transform {
+    let i = usize::from(u8::from(a));
+    let j = usize::from(u8::from(b));
+    Transform::from_str(CAYLEY[i][j]).unwrap()
+}
+
+/// Finds the inverse of a transform by searching the Cayley table.
+fn table_inverse(a: Transform) -> Transform {
+    ALL_TRANSFORMS
+        .into_iter()
+        .find(|&b| table(a, b) == Transform::Identity)
+        .unwrap()
+}
+
+/// Raises a transform to a power by repeated lookup in the Cayley table.
+fn table_pow(a: Transform, n: i32) -> Transform {
+    let base = if n < 0 { table_inverse(a) } else { a };
+    (0..n.unsigned_abs()).fold(Transform::Identity, |acc, _| table(acc, base))
+}
+
+#[test]
+fn test_compose_matches_table() {
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            assert_eq!(a.compose(b), table(a, b), "failed: {:?} * {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_inverse_matches_table() {
+    for a in ALL_TRANSFORMS {
+        assert_eq!(a.inverse(), table_inverse(a), "failed: {:?}", a);
+        assert_eq!(-a, a.inverse());
+    }
+}
+
+#[test]
+fn test_order_matches_table() {
+    for a in ALL_TRANSFORMS {
+        let expected = (1..=8).find(|&n| table_pow(a, n) == Transform::Identity).unwrap();
+        assert_eq!(i32::from(a.order()), expected, "failed: {:?}", a);
+    }
+}
+
+#[test]
+fn test_pow_matches_table() {
+    for a in ALL_TRANSFORMS {
+        for n in -9..=9 {
+            assert_eq!(a.pow(n), table_pow(a, n), "failed: {:?}^{}", a, n);
+        }
+    }
+}
+
+#[test]
+fn test_pow_extremes() {
+    for a in ALL_TRANSFORMS {
+        assert_eq!(a.pow(i32::MAX), table_pow(a, i32::MAX.rem_euclid(8)));
+        assert_eq!(a.pow(i32::MIN), table_pow(a, i32::MIN.rem_euclid(8)));
+    }
+}
+
+#[test]
+fn test_conjugate_matches_table() {
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            let expected = table(table(a, b), table_inverse(a));
+            assert_eq!(a.conjugate(b), expected, "failed: {:?} conjugating {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_commutator_matches_table() {
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            let expected = table(table(table(a, b), table_inverse(a)), table_inverse(b));
+            assert_eq!(a.commutator(b), expected, "failed: [{:?}, {:?}]", a, b);
+
+            let commute = table(a, b) == table(b, a);
+            assert_eq!(a.commutator(b) == Transform::Identity, commute);
+        }
+    }
+}
+
+#[test]
+fn test_commutators_lie_in_centre() {
+    // The commutator subgroup of D4 is {I, U}.
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            assert!(matches!(a.commutator(b), Transform::Identity | Transform::Rotate180));
+        }
+    }
+}
+
+#[test]
+fn test_determinant() {
+    for a in ALL_TRANSFORMS {
+        let expected = if a.is_flip() { -1 } else { 1 };
+        assert_eq!(a.determinant(), expected, "failed: {:?}", a);
+    }
+}
+
+#[test]
+fn test_determinant_is_homomorphism() {
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            assert_eq!(table(a, b).determinant(), a.determinant() * b.determinant());
+        }
+    }
+}
+
+#[test]
+fn test_const_evaluation() {
+    const CONJUGATE: Transform = Transform::Rotate90.conjugate(Transform::FlipHorizontal);
+    const POWER: Transform = Transform::Rotate90.pow(-1);
+    const ORDER: u8 = Transform::FlipDiagonal.order();
+    assert_eq!(CONJUGATE, Transform::FlipVertical);
+    assert_eq!(POWER, Transform::Rotate270);
+    assert_eq!(ORDER, 2);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "array")]
+
+use nav::{ALL_TRANSFORMS, Transform};
+use ndarray::{Array2, ErrorKind, s};
+
+#[test]
+fn test_apply_in_place_matches_mul() {
+    for size in 0..9 {
+        let arr = Array2::from_shape_fn((size, size), |(r, c)| r * size + c);
+        for transform in ALL_TRANSFORMS {
+            let mut result = arr.clone();
+            transform.apply_in_place(&mut result).unwrap();
+            assert_eq!(result, transform * &arr, "failed: {:?} on {}x{}", transform, size, size);
+        }
+    }
+}
+
+#[test]
+fn test_apply_in_place_on_strided_view() {
+    let mut arr = Array2::from_shape_fn((8, 8), |(r, c)| r * 8 + c);
+    let expected = Transform::Rotate90 * arr.slice(s![1..7;2, 1..7;2]);
+
+    let mut view = arr.slice_mut(s![1..7;2, 1..7;2]);
+    Transform::Rotate90.apply_in_place(&mut view).unwrap();
+    assert_eq!(view, expected);
+}
+
+#[test]
+fn test_apply_in_place_composition() {
+    let arr = Array2::from_shape_fn((5, 5), |(r, c)| r * 5 + c);
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            let mut result = arr.clone();
+            a.apply_in_place(&mut result).unwrap();
+            b.apply_in_place(&mut result).unwrap();
+            assert_eq!(result, (a * b) * &arr, "failed: {:?} * {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_apply_in_place_non_square_error() {
+    for transform in ALL_TRANSFORMS {
+        let mut arr = Array2::<u8>::zeros((3, 4));
+        let err = transform.apply_in_place(&mut arr).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    }
+}
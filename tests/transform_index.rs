@@ -0,0 +1,124 @@
+use nav::{ALL_TRANSFORMS, Transform};
+use std::collections::HashSet;
+
+#[test]
+fn test_transform_swaps_axes() {
+    assert!(!Transform::Identity.swaps_axes());
+    assert!(Transform::Rotate90.swaps_axes());
+    assert!(!Transform::Rotate180.swaps_axes());
+    assert!(Transform::Rotate270.swaps_axes());
+    assert!(!Transform::FlipHorizontal.swaps_axes());
+    assert!(Transform::FlipDiagonal.swaps_axes());
+    assert!(!Transform::FlipVertical.swaps_axes());
+    assert!(Transform::FlipAntiDiagonal.swaps_axes());
+}
+
+#[test]
+fn test_apply_dims() {
+    for transform in ALL_TRANSFORMS {
+        let expected = if transform.swaps_axes() { (5, 3) } else { (3, 5) };
+        assert_eq!(transform.apply_dims((3, 5)), expected, "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn test_apply_index_corners() {
+    // The top-left corner of a 3x5 grid.
+    const TEST_CASES: &[(Transform, (usize, usize))] = &[
+        (Transform::Identity, (0, 0)),
+        (Transform::Rotate90, (0, 2)),
+        (Transform::Rotate180, (2, 4)),
+        (Transform::Rotate270, (4, 0)),
+        (Transform::FlipHorizontal, (0, 4)),
+        (Transform::FlipDiagonal, (0, 0)),
+        (Transform::FlipVertical, (2, 0)),
+        (Transform::FlipAntiDiagonal, (4, 2)),
+    ];
+
+    for &(transform, expected) in TEST_CASES {
+        assert_eq!(transform.apply_index((3, 5), (0, 0)), expected, "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn test_apply_index_is_bijection() {
+    for transform in ALL_TRANSFORMS {
+        for rows in 1..6 {
+            for cols in 1..6 {
+                let (out_rows, out_cols) = transform.apply_dims((rows, cols));
+                let mut seen = HashSet::new();
+                for row in 0..rows {
+                    for col in 0..cols {
+                        let (r, c) = transform.apply_index((rows, cols), (row, col));
+                        assert!(r < out_rows && c < out_cols);
+                        assert!(
+                            seen.insert((r, c)),
+                            "failed: {:?} maps two cells to ({}, {})",
+                            transform,
+                            r,
+                            c
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_apply_index_inverse() {
+    for transform in ALL_TRANSFORMS {
+        let dims = (4, 7);
+        let out_dims = transform.apply_dims(dims);
+        for row in 0..dims.0 {
+            for col in 0..dims.1 {
+                let index = transform.apply_index(dims, (row, col));
+                assert_eq!((-transform).apply_index(out_dims, index), (row, col));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_apply_index_composition() {
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            let dims = (3, 4);
+            let mid_dims = a.apply_dims(dims);
+            for row in 0..dims.0 {
+                for col in 0..dims.1 {
+                    // Array transforms apply the left-hand operand first.
+                    let expected = b.apply_index(mid_dims, a.apply_index(dims, (row, col)));
+                    assert_eq!((a * b).apply_index(dims, (row, col)), expected, "failed: {:?} * {:?}", a, b);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds")]
+fn test_apply_index_out_of_bounds() {
+    let _ = Transform::Rotate90.apply_index((3, 5), (3, 0));
+}
+
+#[cfg(feature = "array")]
+#[test]
+fn test_apply_index_matches_array_mul() {
+    use ndarray::Array2;
+
+    // Property: for every transform and every shape, the element at `(row, col)` of the input
+    // is found at `apply_index(dims, (row, col))` of the output, whose shape is `apply_dims(dims)`.
+    for transform in ALL_TRANSFORMS {
+        for rows in 1..7 {
+            for cols in 1..7 {
+                let arr = Array2::from_shape_fn((rows, cols), |(r, c)| r * cols + c);
+                let out = transform * &arr;
+                assert_eq!(out.dim(), transform.apply_dims((rows, cols)));
+                for ((row, col), &value) in arr.indexed_iter() {
+                    assert_eq!(out[transform.apply_index((rows, cols), (row, col))], value);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use nav::{ALL_TRANSFORMS, Coord, Transform};
+
+/// Multiplies two 2x2 matrices.
+fn product(a: [[i8; 2]; 2], b: [[i8; 2]; 2]) -> [[i8; 2]; 2] {
+    let mut result = [[0; 2]; 2];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = a[i][0] * b[0][j] + a[i][1] * b[1][j];
+        }
+    }
+    result
+}
+
+#[test]
+fn test_transform_to_matrix() {
+    assert_eq!(Transform::Identity.to_matrix(), [[1, 0], [0, 1]]);
+    assert_eq!(Transform::Rotate90.to_matrix(), [[0, 1], [-1, 0]]);
+    assert_eq!(Transform::FlipHorizontal.to_matrix(), [[-1, 0], [0, 1]]);
+    assert_eq!(Transform::FlipDiagonal.to_matrix(), [[0, 1], [1, 0]]);
+}
+
+#[test]
+fn test_transform_matrix_matches_coord() {
+    let coord = Coord::new(2, 5);
+    for transform in ALL_TRANSFORMS {
+        let [[a, b], [c, d]] = transform.to_matrix().map(|row| row.map(i32::from));
+        assert_eq!(
+            coord * transform,
+            Coord::new(a * coord.x + b * coord.y, c * coord.x + d * coord.y),
+            "failed: {:?}",
+            transform
+        );
+    }
+}
+
+#[test]
+fn test_transform_matrix_determinant() {
+    for transform in ALL_TRANSFORMS {
+        let [[a, b], [c, d]] = transform.to_matrix();
+        assert_eq!(a * d - b * c, transform.determinant());
+    }
+}
+
+#[test]
+fn test_transform_matrix_composition() {
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            assert_eq!((a * b).to_matrix(), product(a.to_matrix(), b.to_matrix()));
+        }
+    }
+}
+
+#[test]
+fn test_transform_from_matrix() {
+    for transform in ALL_TRANSFORMS {
+        assert_eq!(Transform::from_matrix(transform.to_matrix()), Some(transform));
+    }
+    assert_eq!(Transform::from_matrix([[0, 0], [0, 0]]), None);
+    assert_eq!(Transform::from_matrix([[2, 0], [0, 1]]), None);
+    assert_eq!(Transform::from_matrix([[1, 1], [0, 1]]), None);
+
+    const ROTATE: Option<Transform> = Transform::from_matrix([[0, -1], [1, 0]]);
+    assert_eq!(ROTATE, Some(Transform::Rotate270));
+}
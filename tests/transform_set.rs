@@ -0,0 +1,76 @@
+use nav::{ALL_TRANSFORMS, Transform, TransformSet};
+
+#[test]
+fn test_transform_set_membership() {
+    let mut set = TransformSet::EMPTY;
+    assert!(set.is_empty());
+    assert!(set.insert(Transform::Rotate90));
+    assert!(!set.insert(Transform::Rotate90));
+    assert!(set.insert(Transform::FlipDiagonal));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(Transform::Rotate90));
+    assert!(!set.contains(Transform::Identity));
+
+    assert!(set.remove(Transform::Rotate90));
+    assert!(!set.remove(Transform::Rotate90));
+    assert_eq!(set, TransformSet::from(Transform::FlipDiagonal));
+}
+
+#[test]
+fn test_transform_set_to_from_u8() {
+    for bits in 0..=u8::MAX {
+        let set = TransformSet::from(bits);
+        assert_eq!(u8::from(set), bits);
+        for transform in ALL_TRANSFORMS {
+            let bit = 1 << u8::from(transform);
+            assert_eq!(set.contains(transform), bits & bit != 0);
+        }
+    }
+}
+
+#[test]
+fn test_transform_set_iter_order() {
+    let set: TransformSet = [Transform::FlipAntiDiagonal, Transform::Identity, Transform::Rotate180]
+        .into_iter()
+        .collect();
+    let members: Vec<_> = set.iter().collect();
+    assert_eq!(
+        members,
+        [Transform::Identity, Transform::Rotate180, Transform::FlipAntiDiagonal]
+    );
+    assert_eq!(set.iter().len(), 3);
+    assert_eq!(TransformSet::ALL.into_iter().collect::<Vec<_>>(), ALL_TRANSFORMS);
+}
+
+#[test]
+fn test_transform_set_operations() {
+    let a = TransformSet::from_transforms(&[Transform::Identity, Transform::Rotate90, Transform::Rotate180]);
+    let b = TransformSet::from_transforms(&[Transform::Rotate180, Transform::FlipVertical]);
+
+    assert_eq!(a | b, TransformSet::from_bits(0b0100_0111));
+    assert_eq!(a & b, TransformSet::from(Transform::Rotate180));
+    assert_eq!(a ^ b, TransformSet::from_bits(0b0100_0011));
+    assert_eq!(a - b, TransformSet::from_bits(0b0000_0011));
+    assert_eq!(!a, TransformSet::from_bits(0b1111_1000));
+    assert!((a & b).is_subset(a));
+    assert!(!a.is_subset(b));
+
+    let mut c = a;
+    c |= b;
+    c &= !TransformSet::from(Transform::Identity);
+    c -= TransformSet::from(Transform::Rotate90);
+    c ^= TransformSet::ALL;
+    assert_eq!(c, !TransformSet::from_bits(0b0100_0100));
+
+    let mut d = TransformSet::EMPTY;
+    d.extend([Transform::Rotate270, Transform::Rotate270]);
+    assert_eq!(d.len(), 1);
+}
+
+#[test]
+fn test_transform_set_to_string() {
+    assert_eq!(TransformSet::EMPTY.to_string(), "");
+    assert_eq!(TransformSet::TRIVIAL.to_string(), "I");
+    assert_eq!(TransformSet::ROTATIONS.to_string(), "IRUL");
+    assert_eq!(TransformSet::ALL.to_string(), "IRUL|/-\\");
+}
@@ -0,0 +1,147 @@
+use nav::{ALL_SUBGROUPS, ALL_TRANSFORMS, Transform, TransformSet};
+
+/// Every subset of D4.
+fn all_subsets() -> impl Iterator<Item = TransformSet> {
+    (0..=u8::MAX).map(TransformSet::from_bits)
+}
+
+#[test]
+fn test_all_subgroups_are_exactly_the_subgroups() {
+    let subgroups: Vec<_> = all_subsets().filter(|set| set.is_subgroup()).collect();
+    assert_eq!(subgroups.len(), 10);
+    for subgroup in subgroups {
+        assert!(ALL_SUBGROUPS.contains(&subgroup), "missing: {}", subgroup);
+    }
+    for subgroup in ALL_SUBGROUPS {
+        assert!(subgroup.is_subgroup(), "not a subgroup: {}", subgroup);
+    }
+}
+
+#[test]
+fn test_all_subgroups_sizes() {
+    let sizes: Vec<_> = ALL_SUBGROUPS.iter().map(|subgroup| subgroup.len()).collect();
+    assert_eq!(sizes, [1, 2, 2, 2, 2, 2, 4, 4, 4, 8]);
+}
+
+#[test]
+fn test_is_subgroup_examples() {
+    assert!(TransformSet::TRIVIAL.is_subgroup());
+    assert!(TransformSet::ROTATIONS.is_subgroup());
+    assert!(TransformSet::ALL.is_subgroup());
+    assert!(!TransformSet::EMPTY.is_subgroup());
+    assert!(!TransformSet::from(Transform::Rotate180).is_subgroup());
+    assert!(!TransformSet::from_transforms(&[Transform::Identity, Transform::Rotate90]).is_subgroup());
+}
+
+#[test]
+fn test_generated_by() {
+    assert_eq!(TransformSet::generated_by(&[]), TransformSet::TRIVIAL);
+    assert_eq!(TransformSet::generated_by(&[Transform::Rotate90]), TransformSet::ROTATIONS);
+    assert_eq!(
+        TransformSet::generated_by(&[Transform::Rotate90, Transform::FlipHorizontal]),
+        TransformSet::ALL
+    );
+    assert_eq!(
+        TransformSet::generated_by(&[Transform::FlipHorizontal, Transform::FlipVertical]),
+        TransformSet::from_transforms(&[
+            Transform::Identity,
+            Transform::Rotate180,
+            Transform::FlipHorizontal,
+            Transform::FlipVertical
+        ])
+    );
+
+    // The generated group is the smallest subgroup containing the generators.
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            let generated = TransformSet::generated_by(&[a, b]);
+            let expected = ALL_SUBGROUPS
+                .into_iter()
+                .filter(|subgroup| subgroup.contains(a) && subgroup.contains(b))
+                .min_by_key(|subgroup| subgroup.len())
+                .unwrap();
+            assert_eq!(generated, expected, "failed: <{:?}, {:?}>", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_cosets_partition_the_group() {
+    for subgroup in ALL_SUBGROUPS {
+        for transversal in [subgroup.left_transversal(), subgroup.right_transversal()] {
+            // Lagrange's theorem
+            assert_eq!(transversal.len() * subgroup.len(), 8);
+        }
+
+        let left = subgroup.left_transversal();
+        let mut covered = TransformSet::EMPTY;
+        for t in left {
+            let coset = subgroup.left_coset(t);
+            assert_eq!(coset.len(), subgroup.len());
+            assert!((covered & coset).is_empty());
+            covered |= coset;
+        }
+        assert_eq!(covered, TransformSet::ALL);
+
+        let right = subgroup.right_transversal();
+        let mut covered = TransformSet::EMPTY;
+        for t in right {
+            let coset = subgroup.right_coset(t);
+            assert!((covered & coset).is_empty());
+            covered |= coset;
+        }
+        assert_eq!(covered, TransformSet::ALL);
+    }
+}
+
+#[test]
+fn test_cosets_match_products() {
+    for subgroup in ALL_SUBGROUPS {
+        for t in ALL_TRANSFORMS {
+            let left: TransformSet = subgroup.iter().map(|h| t * h).collect();
+            let right: TransformSet = subgroup.iter().map(|h| h * t).collect();
+            assert_eq!(subgroup.left_coset(t), left);
+            assert_eq!(t * subgroup, left);
+            assert_eq!(subgroup.right_coset(t), right);
+            assert_eq!(subgroup * t, right);
+        }
+    }
+}
+
+#[test]
+fn test_normalizer() {
+    for subgroup in ALL_SUBGROUPS {
+        let normalizer = subgroup.normalizer();
+        assert!(normalizer.is_subgroup());
+        assert!(subgroup.is_subset(normalizer));
+        for t in ALL_TRANSFORMS {
+            let expected = subgroup.left_coset(t) == subgroup.right_coset(t);
+            assert_eq!(normalizer.contains(t), expected, "failed: {:?} normalizing {}", t, subgroup);
+        }
+    }
+
+    // Every subgroup of index 2 is normal.
+    for subgroup in ALL_SUBGROUPS {
+        if subgroup.len() == 4 {
+            assert!(subgroup.is_normal_subgroup());
+        }
+    }
+
+    // The reflection subgroups are normalized by the Klein four-group containing them.
+    let flip_h = TransformSet::generated_by(&[Transform::FlipHorizontal]);
+    assert_eq!(
+        flip_h.normalizer(),
+        TransformSet::generated_by(&[Transform::FlipHorizontal, Transform::FlipVertical])
+    );
+    assert!(!flip_h.is_normal_subgroup());
+    assert!(TransformSet::generated_by(&[Transform::Rotate180]).is_normal_subgroup());
+}
+
+#[test]
+fn test_conjugate_set() {
+    let flip_h = TransformSet::generated_by(&[Transform::FlipHorizontal]);
+    assert_eq!(
+        flip_h.conjugate(Transform::Rotate90),
+        TransformSet::generated_by(&[Transform::FlipVertical])
+    );
+}
@@ -1,4 +1,4 @@
-use nav::{ALL_TRANSFORMS, Transform};
+use nav::{ALL_TRANSFORMS, ParseError, Transform};
 use std::str::FromStr;
 
 #[test]
@@ -43,7 +43,13 @@ fn test_transform_from_str_error() {
     assert!(Transform::from_str("456").is_err());
 
     let err = Transform::from_str("Invalid").unwrap_err();
-    assert_eq!(err, "Invalid transform, expected one of: I, R, U, L, |, /, -, \\");
+    assert_eq!(err, ParseError::InvalidTransform("Invalid".into()));
+    assert_eq!(err.input(), "Invalid");
+    assert_eq!(err.expected(), "I, R, U, L, |, /, -, \\");
+    assert_eq!(
+        err.to_string(),
+        "Invalid transform \"Invalid\", expected one of: I, R, U, L, |, /, -, \\"
+    );
 }
 
 #[test]
@@ -0,0 +1,67 @@
+#![cfg(feature = "array")]
+
+use nav::ALL_TRANSFORMS;
+use ndarray::{Array2, arr2};
+use std::ptr;
+
+#[test]
+fn test_view_matches_mul() {
+    let arr = Array2::from_shape_fn((3, 5), |(r, c)| r * 5 + c);
+    for transform in ALL_TRANSFORMS {
+        assert_eq!(transform.view(&arr), transform * &arr, "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn test_view_of_view_matches_mul() {
+    let arr = Array2::from_shape_fn((4, 6), |(r, c)| r * 6 + c);
+    let sliced = arr.slice(ndarray::s![1.., ..;2]);
+    for transform in ALL_TRANSFORMS {
+        assert_eq!(transform.view(&sliced), transform * sliced, "failed: {:?}", transform);
+    }
+}
+
+#[test]
+fn test_view_does_not_copy() {
+    let arr = Array2::from_shape_fn((3, 5), |(r, c)| r * 5 + c);
+    for transform in ALL_TRANSFORMS {
+        let view = transform.view(&arr);
+        for (index, value) in arr.indexed_iter() {
+            assert!(ptr::eq(&view[transform.apply_index(arr.dim(), index)], value));
+        }
+    }
+}
+
+#[test]
+fn test_view_composition() {
+    let arr = Array2::from_shape_fn((3, 4), |(r, c)| r * 4 + c);
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            assert_eq!(b.view(&a.view(&arr)), (a * b).view(&arr), "failed: {:?} * {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_view_mut_writes_through() {
+    for transform in ALL_TRANSFORMS {
+        let mut arr = arr2(&[[0, 0, 0], [0, 0, 0]]);
+        let dims = arr.dim();
+        {
+            let mut view = transform.view_mut(&mut arr);
+            view[[0, 0]] = 1;
+        }
+        let index = (-transform).apply_index(transform.apply_dims(dims), (0, 0));
+        assert_eq!(arr[index], 1, "failed: {:?}", transform);
+        assert_eq!(arr.sum(), 1);
+    }
+}
+
+#[test]
+fn test_view_mut_matches_mul() {
+    for transform in ALL_TRANSFORMS {
+        let mut arr = Array2::from_shape_fn((3, 5), |(r, c)| r * 5 + c);
+        let expected = transform * &arr;
+        assert_eq!(transform.view_mut(&mut arr), expected, "failed: {:?}", transform);
+    }
+}
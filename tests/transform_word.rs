@@ -0,0 +1,112 @@
+#![cfg(feature = "alloc")]
+
+use nav::{ALL_TRANSFORMS, ParseError, Transform, TransformWord};
+use std::str::FromStr;
+
+#[test]
+fn test_parse_word() {
+    assert_eq!(Transform::parse_word("").unwrap(), Transform::Identity);
+    assert_eq!(Transform::parse_word("R").unwrap(), Transform::Rotate90);
+    assert_eq!(Transform::parse_word("RR").unwrap(), Transform::Rotate180);
+    assert_eq!(Transform::parse_word("rrr").unwrap(), Transform::Rotate270);
+    assert_eq!(Transform::parse_word("||").unwrap(), Transform::Identity);
+    assert_eq!(
+        Transform::parse_word("RR|/").unwrap(),
+        Transform::Rotate90 * Transform::Rotate90 * Transform::FlipHorizontal * Transform::FlipDiagonal
+    );
+}
+
+#[test]
+fn test_parse_word_matches_pairwise_products() {
+    for a in ALL_TRANSFORMS {
+        for b in ALL_TRANSFORMS {
+            let word = format!("{a}{b}");
+            assert_eq!(Transform::parse_word(&word).unwrap(), a * b, "failed: {}", word);
+        }
+    }
+}
+
+#[test]
+fn test_parse_word_error_position() {
+    let err = Transform::parse_word("RR|x/").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::InvalidTransformWord {
+            symbol: "x".into(),
+            position: 3
+        }
+    );
+    assert_eq!(err.input(), "x");
+    assert_eq!(err.position(), Some(3));
+    assert_eq!(
+        err.to_string(),
+        "Invalid transform symbol \"x\" at position 3, expected one of: I, R, U, L, |, /, -, \\"
+    );
+
+    // Positions are counted in characters, not bytes.
+    let err = Transform::parse_word("é|é").unwrap_err();
+    assert_eq!(err.position(), Some(0));
+    assert_eq!(err.input(), "é");
+
+    let err = Transform::parse_word("R R").unwrap_err();
+    assert_eq!(err.position(), Some(1));
+}
+
+#[test]
+fn test_transform_word_from_str() {
+    let word = TransformWord::from_str("RR|/").unwrap();
+    assert_eq!(
+        word.transforms(),
+        &[
+            Transform::Rotate90,
+            Transform::Rotate90,
+            Transform::FlipHorizontal,
+            Transform::FlipDiagonal
+        ]
+    );
+    assert_eq!(word.len(), 4);
+    assert!(!word.is_empty());
+    assert_eq!(word.reduce(), Transform::parse_word("RR|/").unwrap());
+
+    let err = "RLQ".parse::<TransformWord>().unwrap_err();
+    assert_eq!(err.position(), Some(2));
+}
+
+#[test]
+fn test_transform_word_roundtrip() {
+    let word: TransformWord = "ur\\-l/|i".parse().unwrap();
+    assert_eq!(word.to_string(), "UR\\-L/|I");
+    assert_eq!(word.to_string().parse::<TransformWord>().unwrap(), word);
+}
+
+#[test]
+fn test_transform_word_reduce_to_canonical_symbol() {
+    let word: TransformWord = "RR|/".parse().unwrap();
+    let canonical = word.reduce().to_string();
+    assert_eq!(canonical.len(), 1);
+    assert_eq!(Transform::from_str(&canonical).unwrap(), word.reduce());
+
+    assert_eq!(TransformWord::new().reduce(), Transform::Identity);
+    assert_eq!(TransformWord::new().to_string(), "");
+}
+
+#[test]
+fn test_transform_word_collect_and_extend() {
+    let mut word: TransformWord = [Transform::Rotate90, Transform::FlipVertical].into_iter().collect();
+    word.push(Transform::Rotate270);
+    word.extend([Transform::Identity]);
+    assert_eq!(word.to_string(), "R-LI");
+    assert_eq!(word.iter().count(), 4);
+    assert_eq!((&word).into_iter().copied().collect::<Vec<_>>(), Vec::from(word.clone()));
+    assert_eq!(TransformWord::from(Vec::from(word.clone())), word);
+}
+
+#[test]
+fn test_from_symbol() {
+    for transform in ALL_TRANSFORMS {
+        let symbol = transform.to_string().chars().next().unwrap();
+        assert_eq!(Transform::from_symbol(symbol), Some(transform));
+    }
+    assert_eq!(Transform::from_symbol('r'), Some(Transform::Rotate90));
+    assert_eq!(Transform::from_symbol('X'), None);
+}
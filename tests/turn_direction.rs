@@ -0,0 +1,65 @@
+use nav::{ALL_DIRECTIONS, ALL_TRANSFORMS, ALL_TURNS, ConversionError, Direction, Transform, Turn};
+
+#[test]
+fn test_direction_add_turn() {
+    assert_eq!(Direction::North + Turn::Straight, Direction::North);
+    assert_eq!(Direction::North + Turn::Right, Direction::East);
+    assert_eq!(Direction::North + Turn::Back, Direction::South);
+    assert_eq!(Direction::North + Turn::Left, Direction::West);
+    assert_eq!(Direction::West + Turn::Right, Direction::North);
+    assert_eq!(Direction::South + Turn::Left, Direction::East);
+
+    let mut heading = Direction::East;
+    heading += Turn::Back;
+    assert_eq!(heading, Direction::West);
+}
+
+#[test]
+fn test_direction_sub_direction() {
+    assert_eq!(Direction::East - Direction::North, Turn::Right);
+    assert_eq!(Direction::North - Direction::East, Turn::Left);
+    assert_eq!(Direction::West - Direction::East, Turn::Back);
+    assert_eq!(Direction::South - Direction::South, Turn::Straight);
+
+    for from in ALL_DIRECTIONS {
+        for to in ALL_DIRECTIONS {
+            assert_eq!(from + (to - from), to, "failed: {:?} to {:?}", from, to);
+        }
+        for turn in ALL_TURNS {
+            assert_eq!((from + turn) - from, turn);
+        }
+    }
+}
+
+#[test]
+fn test_turn_arithmetic() {
+    assert_eq!(Turn::Right + Turn::Right, Turn::Back);
+    assert_eq!(Turn::Left + Turn::Back, Turn::Right);
+    assert_eq!(-Turn::Right, Turn::Left);
+    assert_eq!(-Turn::Back, Turn::Back);
+    assert_eq!(-Turn::Straight, Turn::Straight);
+
+    for a in ALL_TURNS {
+        assert_eq!(a + -a, Turn::Straight);
+        for b in ALL_TURNS {
+            for direction in ALL_DIRECTIONS {
+                assert_eq!(direction + (a + b), direction + a + b);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_turn_transform() {
+    for turn in ALL_TURNS {
+        let transform = Transform::from(turn);
+        assert!(!transform.is_flip());
+        assert_eq!(Turn::try_from(transform), Ok(turn));
+        for direction in ALL_DIRECTIONS {
+            assert_eq!(direction * transform, direction + turn);
+        }
+    }
+    for transform in ALL_TRANSFORMS.into_iter().filter(|transform| transform.is_flip()) {
+        assert_eq!(Turn::try_from(transform), Err(ConversionError::FlipTransform(transform)));
+    }
+}
@@ -0,0 +1,64 @@
+use nav::{ALL_DIRECTIONS, ALL_TURNS, Direction, ParseError, Turn};
+
+#[test]
+fn test_turn_to_from_str() {
+    let cases = [
+        ("F", Turn::Straight),
+        ("R", Turn::Right),
+        ("B", Turn::Back),
+        ("L", Turn::Left),
+        ("straight", Turn::Straight),
+        ("Right", Turn::Right),
+        ("BACK", Turn::Back),
+        ("l", Turn::Left),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(input.parse::<Turn>(), Ok(expected), "failed: {input}");
+    }
+    for turn in ALL_TURNS {
+        assert_eq!(turn.to_string().parse::<Turn>(), Ok(turn));
+    }
+}
+
+#[test]
+fn test_turn_to_from_u8() {
+    for (value, turn) in ALL_TURNS.into_iter().enumerate() {
+        let value = u8::try_from(value).unwrap();
+        assert_eq!(u8::from(turn), value);
+        assert_eq!(Turn::try_from(value), Ok(turn));
+    }
+    assert!(Turn::try_from(4).is_err());
+}
+
+#[test]
+fn test_turn_from_str_error() {
+    let err = "U".parse::<Turn>().unwrap_err();
+    assert_eq!(err, ParseError::InvalidTurn("U".into()));
+    assert_eq!(
+        err.to_string(),
+        "Invalid turn \"U\", expected one of: F, R, B, L, Straight, Right, Back, Left"
+    );
+}
+
+#[test]
+fn test_turtle_program() {
+    let program = "FRFFLB";
+    let mut heading = Direction::North;
+    let mut headings = Vec::new();
+    for symbol in program.chars() {
+        heading += symbol.to_string().parse::<Turn>().unwrap();
+        headings.push(heading);
+    }
+    assert_eq!(
+        headings,
+        [
+            Direction::North,
+            Direction::East,
+            Direction::East,
+            Direction::East,
+            Direction::North,
+            Direction::South
+        ]
+    );
+    assert!(ALL_DIRECTIONS.contains(&heading));
+}